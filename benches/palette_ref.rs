@@ -0,0 +1,52 @@
+//! Compares copying palettes out of [`ThemeName::palette`] with borrowing
+//! them from [`ThemeName::palette_ref`].
+//!
+//! Run with `cargo bench --bench palette_ref`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use ratatui::style::Color;
+use ratatui_themes::{ThemeName, ThemePalette};
+
+/// Palette lookups per measurement.
+const LOOKUPS: u32 = 10_000_000;
+
+/// Stand-in for a widget that reads a few colors from the palette.
+fn draw(palette: &ThemePalette) -> Color {
+    black_box(palette.fg);
+    black_box(palette.accent);
+    palette.bg
+}
+
+fn by_value(themes: &[ThemeName]) -> Duration {
+    let start = Instant::now();
+    for i in 0..LOOKUPS {
+        let theme = themes[i as usize % themes.len()];
+        black_box(draw(&black_box(theme).palette()));
+    }
+    start.elapsed()
+}
+
+fn by_ref(themes: &[ThemeName]) -> Duration {
+    let start = Instant::now();
+    for i in 0..LOOKUPS {
+        let theme = themes[i as usize % themes.len()];
+        black_box(draw(black_box(theme).palette_ref()));
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let themes = ThemeName::all();
+    let by_value = by_value(themes);
+    let by_ref = by_ref(themes);
+
+    println!("{LOOKUPS} palette lookups");
+    println!("palette():     {by_value:?}");
+    println!("palette_ref(): {by_ref:?}");
+    println!(
+        "speedup:       {:.1}x",
+        by_value.as_secs_f64() / by_ref.as_secs_f64()
+    );
+}
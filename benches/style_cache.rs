@@ -0,0 +1,63 @@
+//! Compares building styles from the palette every frame with reading them
+//! from a [`ThemeStyleCache`].
+//!
+//! Run with `cargo bench --bench style_cache`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use ratatui::style::{Modifier, Style};
+use ratatui_themes::{Theme, ThemeName, ThemeStyleCache};
+
+/// Frames to simulate per measurement.
+const FRAMES: u32 = 100_000;
+/// Widgets styled per frame.
+const WIDGETS: usize = 40;
+
+fn recomputed(theme: Theme) -> Duration {
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        for _ in 0..WIDGETS {
+            let palette = black_box(theme).palette();
+            black_box(Style::new().fg(palette.fg).bg(palette.bg));
+            black_box(Style::new().fg(palette.accent).add_modifier(Modifier::BOLD));
+            black_box(
+                Style::new()
+                    .fg(palette.on_selection())
+                    .bg(palette.selection),
+            );
+            black_box(Style::new().fg(palette.error));
+        }
+    }
+    start.elapsed()
+}
+
+fn cached(theme: Theme) -> Duration {
+    let mut cache = ThemeStyleCache::new(theme);
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        cache.update(black_box(theme));
+        for _ in 0..WIDGETS {
+            let styles = black_box(&cache);
+            black_box(styles.base());
+            black_box(styles.title());
+            black_box(styles.selection());
+            black_box(styles.error());
+        }
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let theme = Theme::new(ThemeName::CatppuccinMocha);
+    let recomputed = recomputed(theme);
+    let cached = cached(theme);
+
+    println!("{FRAMES} frames x {WIDGETS} widgets");
+    println!("recomputed: {recomputed:?}");
+    println!("cached:     {cached:?}");
+    println!(
+        "speedup:    {:.1}x",
+        recomputed.as_secs_f64() / cached.as_secs_f64()
+    );
+}
@@ -0,0 +1,254 @@
+//! Step-by-step palette construction.
+//!
+//! This module contains [`ThemePaletteBuilder`], which sets a palette's
+//! colors one at a time, either from scratch or on top of a base palette,
+//! optionally validating the result with [`ThemePalette::validate`] before
+//! handing it out.
+
+use crate::palette::{PaletteValidationError, ThemePalette};
+use ratatui::style::Color;
+
+/// Builds a [`ThemePalette`] one color at a time.
+///
+/// Start from scratch with [`ThemePalette::builder()`] (or
+/// [`new`](Self::new)) and set all ten colors, or start from an existing
+/// palette with [`from_palette`](Self::from_palette) and set only the
+/// colors you want to change. Then finish with:
+///
+/// - [`build_checked`](Self::build_checked), which also runs
+///   [`ThemePalette::validate`] and rejects unreadable palettes, or
+/// - [`build`](Self::build), which only requires every color to be set,
+///   for when you know what you're doing.
+///
+/// The resulting palette has the same helpers as a built-in one and, with
+/// the `widgets` feature, can be previewed with `ThemePicker::custom`.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_themes::{Color, ThemeName, ThemePalette, ThemePaletteBuilder};
+///
+/// let brand = ThemePalette::builder()
+///     .accent(Color::Rgb(0, 120, 212))
+///     .secondary(Color::Rgb(135, 100, 184))
+///     .bg(Color::Rgb(24, 24, 27))
+///     .fg(Color::Rgb(228, 228, 231))
+///     .muted(Color::Rgb(161, 161, 170))
+///     .selection(Color::Rgb(63, 63, 70))
+///     .error(Color::Rgb(239, 68, 68))
+///     .warning(Color::Rgb(245, 158, 11))
+///     .success(Color::Rgb(34, 197, 94))
+///     .info(Color::Rgb(56, 189, 248))
+///     .build_checked()
+///     .expect("complete, readable palette");
+/// assert_eq!(brand.accent, Color::Rgb(0, 120, 212));
+///
+/// // Or tweak an existing palette.
+/// let palette = ThemePaletteBuilder::from_palette(ThemeName::Nord.palette())
+///     .accent(Color::Rgb(235, 203, 139))
+///     .build_checked()
+///     .expect("readable palette");
+///
+/// assert_eq!(palette.accent, Color::Rgb(235, 203, 139));
+/// assert_eq!(palette.bg, ThemeName::Nord.palette().bg);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ThemePaletteBuilder {
+    pub(crate) accent: Option<Color>,
+    pub(crate) secondary: Option<Color>,
+    pub(crate) bg: Option<Color>,
+    pub(crate) fg: Option<Color>,
+    pub(crate) muted: Option<Color>,
+    pub(crate) selection: Option<Color>,
+    pub(crate) error: Option<Color>,
+    pub(crate) warning: Option<Color>,
+    pub(crate) success: Option<Color>,
+    pub(crate) info: Option<Color>,
+}
+
+/// Generates a setter for each palette field.
+macro_rules! setters {
+    ($($field:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Set the `", stringify!($field), "` color.")]
+            #[must_use]
+            pub const fn $field(mut self, color: Color) -> Self {
+                self.$field = Some(color);
+                self
+            }
+        )*
+    };
+}
+
+impl ThemePaletteBuilder {
+    /// Create a builder with no colors set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            accent: None,
+            secondary: None,
+            bg: None,
+            fg: None,
+            muted: None,
+            selection: None,
+            error: None,
+            warning: None,
+            success: None,
+            info: None,
+        }
+    }
+
+    /// Create a builder with every color taken from `base`.
+    #[must_use]
+    pub const fn from_palette(base: ThemePalette) -> Self {
+        Self {
+            accent: Some(base.accent),
+            secondary: Some(base.secondary),
+            bg: Some(base.bg),
+            fg: Some(base.fg),
+            muted: Some(base.muted),
+            selection: Some(base.selection),
+            error: Some(base.error),
+            warning: Some(base.warning),
+            success: Some(base.success),
+            info: Some(base.info),
+        }
+    }
+
+    setters!(accent, secondary, bg, fg, muted, selection, error, warning, success, info);
+
+    /// Returns the palette without checking its contrast.
+    ///
+    /// A builder created with [`from_palette`](Self::from_palette) has every
+    /// color set, so this cannot fail.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PaletteValidationError::MissingField`] naming the first
+    /// color (in field order) that was never set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, PaletteValidationError, ThemePalette};
+    ///
+    /// let result = ThemePalette::builder().accent(Color::Rgb(0, 120, 212)).build();
+    /// assert_eq!(result, Err(PaletteValidationError::MissingField { field: "secondary" }));
+    /// ```
+    pub fn build(self) -> Result<ThemePalette, PaletteValidationError> {
+        let require = |color: Option<Color>, field| {
+            color.ok_or(PaletteValidationError::MissingField { field })
+        };
+        Ok(ThemePalette {
+            accent: require(self.accent, "accent")?,
+            secondary: require(self.secondary, "secondary")?,
+            bg: require(self.bg, "bg")?,
+            fg: require(self.fg, "fg")?,
+            muted: require(self.muted, "muted")?,
+            selection: require(self.selection, "selection")?,
+            error: require(self.error, "error")?,
+            warning: require(self.warning, "warning")?,
+            success: require(self.success, "success")?,
+            info: require(self.info, "info")?,
+        })
+    }
+
+    /// Returns the palette if every color is set and it passes [`ThemePalette::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`PaletteValidationError`] found: a missing color
+    /// as in [`build`](Self::build), then any contrast problem. Contrast
+    /// messages name the field that failed against `bg` and the measured
+    /// ratio, e.g.
+    /// `"Palette error is nearly invisible against bg (1.02:1)"`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, PaletteValidationError, ThemeName, ThemePaletteBuilder};
+    ///
+    /// let result = ThemePaletteBuilder::from_palette(ThemeName::Dracula.palette())
+    ///     .error(Color::Rgb(42, 44, 56))
+    ///     .build_checked();
+    ///
+    /// let err = result.unwrap_err();
+    /// assert!(matches!(err, PaletteValidationError::InvisibleColor { field: "error", .. }));
+    /// assert!(err.to_string().starts_with("Palette error is nearly invisible against bg"));
+    /// ```
+    pub fn build_checked(self) -> Result<ThemePalette, PaletteValidationError> {
+        let palette = self.build()?;
+        palette.validate()?;
+        Ok(palette)
+    }
+}
+
+impl From<ThemePalette> for ThemePaletteBuilder {
+    fn from(base: ThemePalette) -> Self {
+        Self::from_palette(base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ThemeName;
+
+    #[test]
+    fn test_build_checked_accepts_readable_palette() {
+        let base = ThemeName::GruvboxLight.palette();
+        let palette = ThemePaletteBuilder::from(base)
+            .secondary(Color::Rgb(7, 102, 120))
+            .info(Color::Rgb(69, 133, 136))
+            .build_checked()
+            .unwrap();
+
+        assert_eq!(palette.secondary, Color::Rgb(7, 102, 120));
+        assert_eq!(palette.info, Color::Rgb(69, 133, 136));
+        assert_eq!(palette.fg, base.fg);
+        assert_eq!(ThemePaletteBuilder::from(base).build(), Ok(base));
+    }
+
+    #[test]
+    fn test_build_checked_rejects_low_contrast() {
+        let builder =
+            ThemePaletteBuilder::from_palette(ThemeName::Nord.palette()).fg(Color::Rgb(60, 66, 80));
+        assert!(matches!(
+            builder.build_checked(),
+            Err(PaletteValidationError::LowTextContrast { .. })
+        ));
+        // The unchecked build still hands the palette out.
+        assert_eq!(builder.build().unwrap().fg, Color::Rgb(60, 66, 80));
+
+        let same = builder.fg(Color::Rgb(46, 52, 64)).build_checked();
+        assert_eq!(
+            same,
+            Err(PaletteValidationError::SameForegroundAndBackground)
+        );
+    }
+
+    #[test]
+    fn test_build_requires_every_color() {
+        let palette = ThemeName::Kanagawa.palette();
+        let mut builder = ThemePalette::builder();
+        for (field, color) in palette.iter_fields() {
+            assert_eq!(
+                builder.build(),
+                Err(PaletteValidationError::MissingField { field })
+            );
+            builder = match field {
+                "accent" => builder.accent(color),
+                "secondary" => builder.secondary(color),
+                "bg" => builder.bg(color),
+                "fg" => builder.fg(color),
+                "muted" => builder.muted(color),
+                "selection" => builder.selection(color),
+                "error" => builder.error(color),
+                "warning" => builder.warning(color),
+                "success" => builder.success(color),
+                _ => builder.info(color),
+            };
+        }
+        assert_eq!(builder.build_checked(), Ok(palette));
+    }
+}
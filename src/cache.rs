@@ -0,0 +1,197 @@
+//! Precomputed styles for render loops.
+//!
+//! This module contains [`ThemeStyleCache`], which builds the commonly used
+//! [`Style`]s for a theme once and hands them out every frame.
+
+use crate::theme::Theme;
+use ratatui::style::{Modifier, Style};
+
+/// Commonly used styles for a [`Theme`], computed once and reused every frame.
+///
+/// Building styles from the palette is cheap, but doing it for dozens of
+/// widgets on every frame adds up. Keep a cache in your app state, call
+/// [`update`](Self::update) whenever the theme may have changed, and have
+/// widgets read the styles from it. The styles are only rebuilt when the theme
+/// actually changes.
+///
+/// | Style | Definition |
+/// |-------|------------|
+/// | [`base`](Self::base) | `fg` on `bg` |
+/// | [`title`](Self::title) | bold `accent` |
+/// | [`emphasis`](Self::emphasis) | `accent` with the theme's [recommended emphasis](crate::ThemeName::recommended_emphasis) |
+/// | [`border`](Self::border) | `muted` |
+/// | [`selection`](Self::selection) | [`on_selection()`](crate::ThemePalette::on_selection) on `selection` |
+/// | [`muted`](Self::muted) | `muted` |
+/// | [`error`](Self::error), [`warning`](Self::warning), [`success`](Self::success), [`info`](Self::info) | the matching semantic color |
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{Block, Paragraph};
+/// use ratatui_themes::{Theme, ThemeName, ThemeStyleCache};
+///
+/// let mut theme = Theme::new(ThemeName::Nord);
+/// let mut styles = ThemeStyleCache::new(theme);
+///
+/// // Every frame:
+/// let block = Block::bordered()
+///     .border_style(styles.border())
+///     .title_style(styles.title());
+/// let body = Paragraph::new("ok").style(styles.success()).block(block);
+///
+/// // After the user switches themes:
+/// theme.next();
+/// assert!(styles.update(theme));
+/// assert!(!styles.update(theme));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThemeStyleCache {
+    theme: Theme,
+    base: Style,
+    title: Style,
+    emphasis: Style,
+    border: Style,
+    selection: Style,
+    muted: Style,
+    error: Style,
+    warning: Style,
+    success: Style,
+    info: Style,
+}
+
+impl ThemeStyleCache {
+    /// Build the styles for `theme`.
+    #[must_use]
+    pub fn new(theme: Theme) -> Self {
+        let palette = theme.palette();
+        Self {
+            theme,
+            base: Style::new().fg(palette.fg).bg(palette.bg),
+            title: Style::new().fg(palette.accent).add_modifier(Modifier::BOLD),
+            emphasis: theme.emphasis_style(),
+            border: Style::new().fg(palette.muted),
+            selection: Style::new()
+                .fg(palette.on_selection())
+                .bg(palette.selection),
+            muted: Style::new().fg(palette.muted),
+            error: Style::new().fg(palette.error),
+            warning: Style::new().fg(palette.warning),
+            success: Style::new().fg(palette.success),
+            info: Style::new().fg(palette.info),
+        }
+    }
+
+    /// Rebuild the styles if `theme` differs from the cached one.
+    ///
+    /// Returns `true` if the styles were rebuilt, which callers can use as a
+    /// redraw trigger.
+    pub fn update(&mut self, theme: Theme) -> bool {
+        if theme == self.theme {
+            return false;
+        }
+        *self = Self::new(theme);
+        true
+    }
+
+    /// Returns the theme the styles were built for.
+    #[must_use]
+    pub const fn theme(&self) -> Theme {
+        self.theme
+    }
+
+    /// Base style for text: `fg` on `bg`.
+    #[must_use]
+    pub const fn base(&self) -> Style {
+        self.base
+    }
+
+    /// Style for titles and headings: bold `accent`.
+    #[must_use]
+    pub const fn title(&self) -> Style {
+        self.title
+    }
+
+    /// Style for emphasized text: `accent` with the theme's recommended emphasis.
+    ///
+    /// See [`Theme::emphasis_style`].
+    #[must_use]
+    pub const fn emphasis(&self) -> Style {
+        self.emphasis
+    }
+
+    /// Style for borders: `muted`.
+    #[must_use]
+    pub const fn border(&self) -> Style {
+        self.border
+    }
+
+    /// Style for selected items: legible text on `selection`.
+    #[must_use]
+    pub const fn selection(&self) -> Style {
+        self.selection
+    }
+
+    /// Style for dimmed text: `muted`.
+    #[must_use]
+    pub const fn muted(&self) -> Style {
+        self.muted
+    }
+
+    /// Style for errors: `error`.
+    #[must_use]
+    pub const fn error(&self) -> Style {
+        self.error
+    }
+
+    /// Style for warnings: `warning`.
+    #[must_use]
+    pub const fn warning(&self) -> Style {
+        self.warning
+    }
+
+    /// Style for success messages: `success`.
+    #[must_use]
+    pub const fn success(&self) -> Style {
+        self.success
+    }
+
+    /// Style for informational messages: `info`.
+    #[must_use]
+    pub const fn info(&self) -> Style {
+        self.info
+    }
+}
+
+impl Default for ThemeStyleCache {
+    fn default() -> Self {
+        Self::new(Theme::default())
+    }
+}
+
+impl From<Theme> for ThemeStyleCache {
+    fn from(theme: Theme) -> Self {
+        Self::new(theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ThemeName;
+
+    #[test]
+    fn test_update_rebuilds_only_on_change() {
+        let mut cache = ThemeStyleCache::new(Theme::new(ThemeName::Nord));
+        assert!(!cache.update(Theme::new(ThemeName::Nord)));
+
+        assert!(cache.update(Theme::new(ThemeName::GruvboxLight)));
+        assert_eq!(
+            cache,
+            ThemeStyleCache::new(Theme::new(ThemeName::GruvboxLight))
+        );
+        assert_eq!(
+            cache.error().fg,
+            Some(ThemeName::GruvboxLight.palette().error)
+        );
+    }
+}
@@ -0,0 +1,578 @@
+//! Color math shared by the palette helpers.
+//!
+//! This module contains the low-level conversions used throughout the crate:
+//! resolving any ratatui [`Color`] to RGB, computing WCAG relative luminance
+//! and contrast ratios, and grading contrast against the WCAG thresholds.
+//! It also contains [`ColorExt`], the public lighten/darken/blend helpers.
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use alloc::{format, string::String};
+use ratatui::style::Color;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Approximate sRGB values for the 16 standard ANSI colors (xterm defaults).
+///
+/// Indexed in ANSI order: black, red, green, yellow, blue, magenta, cyan,
+/// white, followed by the bright variants.
+const ANSI_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),       // Black
+    (205, 0, 0),     // Red
+    (0, 205, 0),     // Green
+    (205, 205, 0),   // Yellow
+    (0, 0, 238),     // Blue
+    (205, 0, 205),   // Magenta
+    (0, 205, 205),   // Cyan
+    (229, 229, 229), // Gray (ANSI white)
+    (127, 127, 127), // DarkGray (ANSI bright black)
+    (255, 0, 0),     // LightRed
+    (0, 255, 0),     // LightGreen
+    (255, 255, 0),   // LightYellow
+    (92, 92, 255),   // LightBlue
+    (255, 0, 255),   // LightMagenta
+    (0, 255, 255),   // LightCyan
+    (255, 255, 255), // White
+];
+
+/// Resolve a color to its RGB channels.
+///
+/// Named ANSI colors map to the xterm defaults and indexed colors follow the
+/// standard 256-color layout (16 ANSI colors, a 6×6×6 cube, and a 24-step
+/// grayscale ramp). [`Color::Reset`] has no fixed value and yields `None`.
+pub(crate) const fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        Color::Black => Some(ANSI_RGB[0]),
+        Color::Red => Some(ANSI_RGB[1]),
+        Color::Green => Some(ANSI_RGB[2]),
+        Color::Yellow => Some(ANSI_RGB[3]),
+        Color::Blue => Some(ANSI_RGB[4]),
+        Color::Magenta => Some(ANSI_RGB[5]),
+        Color::Cyan => Some(ANSI_RGB[6]),
+        Color::Gray => Some(ANSI_RGB[7]),
+        Color::DarkGray => Some(ANSI_RGB[8]),
+        Color::LightRed => Some(ANSI_RGB[9]),
+        Color::LightGreen => Some(ANSI_RGB[10]),
+        Color::LightYellow => Some(ANSI_RGB[11]),
+        Color::LightBlue => Some(ANSI_RGB[12]),
+        Color::LightMagenta => Some(ANSI_RGB[13]),
+        Color::LightCyan => Some(ANSI_RGB[14]),
+        Color::White => Some(ANSI_RGB[15]),
+        Color::Indexed(i) => Some(indexed_to_rgb(i)),
+        Color::Reset => None,
+    }
+}
+
+/// Encode a color as four bytes that are stable across runs and platforms.
+///
+/// The first byte tags the variant (RGB, indexed, named, reset) and the rest
+/// carry its data, so distinct colors never share an encoding.
+pub(crate) const fn stable_bytes(color: Color) -> [u8; 4] {
+    match color {
+        Color::Rgb(r, g, b) => [0, r, g, b],
+        Color::Indexed(i) => [1, i, 0, 0],
+        Color::Reset => [3, 0, 0, 0],
+        Color::Black => [2, 0, 0, 0],
+        Color::Red => [2, 1, 0, 0],
+        Color::Green => [2, 2, 0, 0],
+        Color::Yellow => [2, 3, 0, 0],
+        Color::Blue => [2, 4, 0, 0],
+        Color::Magenta => [2, 5, 0, 0],
+        Color::Cyan => [2, 6, 0, 0],
+        Color::Gray => [2, 7, 0, 0],
+        Color::DarkGray => [2, 8, 0, 0],
+        Color::LightRed => [2, 9, 0, 0],
+        Color::LightGreen => [2, 10, 0, 0],
+        Color::LightYellow => [2, 11, 0, 0],
+        Color::LightBlue => [2, 12, 0, 0],
+        Color::LightMagenta => [2, 13, 0, 0],
+        Color::LightCyan => [2, 14, 0, 0],
+        Color::White => [2, 15, 0, 0],
+    }
+}
+
+/// Resolve a 256-color palette index to RGB.
+const fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    match index {
+        0..=15 => ANSI_RGB[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[((i / 6) % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+/// Squared Euclidean distance between two RGB triples.
+pub(crate) fn distance_sq((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| u32::from(x.abs_diff(y)).pow(2);
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+/// ITU-R BT.601 perceived brightness of a color, from 0 to 255.
+///
+/// Returns `None` for colors that cannot be resolved to RGB.
+pub(crate) const fn brightness(color: Color) -> Option<u32> {
+    match to_rgb(color) {
+        Some((r, g, b)) => Some((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000),
+        None => None,
+    }
+}
+
+/// Linearize a single sRGB channel per WCAG 2.1.
+fn linearize(channel: u8) -> f64 {
+    let c = f64::from(channel) / 255.0;
+    if c <= 0.039_28 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// WCAG relative luminance of a color in the range `0.0..=1.0`.
+///
+/// Returns `None` for colors that cannot be resolved to RGB.
+pub(crate) fn luminance(color: Color) -> Option<f64> {
+    let (r, g, b) = to_rgb(color)?;
+    Some(0.0722f64.mul_add(
+        linearize(b),
+        0.2126f64.mul_add(linearize(r), 0.7152 * linearize(g)),
+    ))
+}
+
+/// WCAG contrast ratio between two colors, from `1.0` to `21.0`.
+///
+/// Colors that cannot be resolved (such as [`Color::Reset`]) are reported as
+/// having no contrast (`1.0`), since their on-screen value is unknown.
+pub(crate) fn contrast_ratio(a: Color, b: Color) -> f64 {
+    match (luminance(a), luminance(b)) {
+        (Some(la), Some(lb)) => {
+            let (lighter, darker) = if la >= lb { (la, lb) } else { (lb, la) };
+            (lighter + 0.05) / (darker + 0.05)
+        }
+        _ => 1.0,
+    }
+}
+
+/// Returns the candidate with the highest contrast ratio against `bg`.
+///
+/// Ties go to the earlier candidate, so list preferred colors first. Colors
+/// without a known RGB value (such as [`Color::Reset`]) have no measurable
+/// contrast, so with a `Reset` background the first candidate is returned.
+/// An empty `candidates` slice yields [`Color::Reset`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_themes::{best_text_color, Color};
+///
+/// let black = Color::Rgb(0, 0, 0);
+/// let white = Color::Rgb(255, 255, 255);
+///
+/// assert_eq!(best_text_color(Color::Rgb(250, 220, 80), &[white, black]), black);
+/// assert_eq!(best_text_color(Color::Rgb(30, 30, 120), &[white, black]), white);
+/// ```
+#[must_use]
+pub fn best_text_color(bg: Color, candidates: &[Color]) -> Color {
+    candidates
+        .iter()
+        .map(|&c| (c, contrast_ratio(c, bg)))
+        .reduce(|best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        })
+        .map_or(Color::Reset, |(c, _)| c)
+}
+
+/// WCAG 2.1 conformance grade for a contrast ratio.
+///
+/// | Grade | Minimum ratio | Meaning |
+/// |-------|---------------|---------|
+/// | [`Aaa`](Self::Aaa) | 7.0 | Enhanced contrast for body text |
+/// | [`Aa`](Self::Aa) | 4.5 | Minimum contrast for body text |
+/// | [`AaLarge`](Self::AaLarge) | 3.0 | Minimum contrast for large or bold text |
+/// | [`Fail`](Self::Fail) | — | Below every WCAG threshold |
+///
+/// Grades are ordered from `Fail` (lowest) to `Aaa` (highest), so they can
+/// be compared, sorted and aggregated with `min`/`max`.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_themes::ContrastGrade;
+///
+/// assert_eq!(ContrastGrade::from_ratio(12.0), ContrastGrade::Aaa);
+/// assert_eq!(ContrastGrade::from_ratio(3.2), ContrastGrade::AaLarge);
+/// assert_eq!(ContrastGrade::from_ratio(1.5), ContrastGrade::Fail);
+/// assert!(ContrastGrade::Aa > ContrastGrade::AaLarge);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ContrastGrade {
+    /// Below 3:1 — not legible enough for any text.
+    Fail,
+    /// At least 3:1 — acceptable for large or bold text only.
+    AaLarge,
+    /// At least 4.5:1 — meets WCAG AA for normal text.
+    Aa,
+    /// At least 7:1 — meets WCAG AAA for normal text.
+    Aaa,
+}
+
+impl ContrastGrade {
+    /// Grade a WCAG contrast ratio.
+    #[must_use]
+    pub fn from_ratio(ratio: f64) -> Self {
+        if ratio >= 7.0 {
+            Self::Aaa
+        } else if ratio >= 4.5 {
+            Self::Aa
+        } else if ratio >= 3.0 {
+            Self::AaLarge
+        } else {
+            Self::Fail
+        }
+    }
+
+    /// Returns the conventional label for the grade (e.g. `"AA Large"`).
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Fail => "Fail",
+            Self::AaLarge => "AA Large",
+            Self::Aa => "AA",
+            Self::Aaa => "AAA",
+        }
+    }
+}
+
+impl core::fmt::Display for ContrastGrade {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// Lightness and blending helpers for [`Color`], e.g. for hover and disabled states.
+///
+/// The helpers only transform [`Color::Rgb`]. Named and indexed colors are
+/// drawn from the terminal's own palette, so their on-screen value is
+/// unknown; they (and [`Color::Reset`]) are passed through unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_themes::{ColorExt, ThemeName};
+///
+/// let palette = ThemeName::Nord.palette();
+/// let hover = palette.accent.lighten(0.1);
+/// let disabled = palette.fg.blend(palette.bg, 0.6);
+///
+/// assert_ne!(hover, palette.accent);
+/// assert_ne!(disabled, palette.fg);
+/// ```
+pub trait ColorExt: Sized {
+    /// Raise the HSL lightness by `amount`, clamped to `0.0..=1.0`.
+    ///
+    /// `amount` is an absolute step, so `lighten(0.5)` takes black to mid
+    /// gray. Hue and saturation are kept.
+    #[must_use]
+    fn lighten(self, amount: f32) -> Self;
+
+    /// Lower the HSL lightness by `amount`, clamped to `0.0..=1.0`.
+    ///
+    /// The inverse of [`lighten`](Self::lighten).
+    #[must_use]
+    fn darken(self, amount: f32) -> Self;
+
+    /// Linearly interpolate each RGB channel toward `other`.
+    ///
+    /// `t` is clamped to `0.0..=1.0`; `0.0` yields `self` and `1.0` yields
+    /// `other`. Returns `self` unchanged unless both colors are RGB.
+    #[must_use]
+    fn blend(self, other: Self, t: f32) -> Self;
+}
+
+impl ColorExt for Color {
+    fn lighten(self, amount: f32) -> Self {
+        shift_lightness(self, amount)
+    }
+
+    fn darken(self, amount: f32) -> Self {
+        shift_lightness(self, -amount)
+    }
+
+    fn blend(self, other: Self, t: f32) -> Self {
+        match (self, other) {
+            (Self::Rgb(..), Self::Rgb(..)) => blend(self, other, t),
+            _ => self,
+        }
+    }
+}
+
+/// Add `delta` to the HSL lightness of an RGB color.
+fn shift_lightness(color: Color, delta: f32) -> Color {
+    let Color::Rgb(r, g, b) = color else {
+        return color;
+    };
+    let (hue, saturation, lightness) = rgb_to_hsl(r, g, b);
+    hsl_to_rgb(hue, saturation, (lightness + delta).clamp(0.0, 1.0))
+}
+
+/// Apply an RGB transform to any color.
+///
+/// Named and indexed colors are resolved with [`to_rgb`] first, so transforms
+/// work on them instead of passing them through. [`Color::Reset`] has no RGB
+/// value and is returned unchanged. When the transform leaves the resolved
+/// channels as they were, the original color is returned, so named colors
+/// survive no-op transforms.
+pub(crate) fn map_rgb(color: Color, f: impl FnOnce(u8, u8, u8) -> (u8, u8, u8)) -> Color {
+    let Some((r, g, b)) = to_rgb(color) else {
+        return color;
+    };
+    let mapped = f(r, g, b);
+    if mapped == (r, g, b) {
+        color
+    } else {
+        Color::Rgb(mapped.0, mapped.1, mapped.2)
+    }
+}
+
+/// Linearly interpolate between two colors; `t = 0.0` yields `a`.
+///
+/// Both colors are resolved with [`to_rgb`]. Returns `a` unchanged if either
+/// is [`Color::Reset`].
+pub(crate) fn blend(a: Color, b: Color, t: f32) -> Color {
+    let Some((r2, g2, b2)) = to_rgb(b) else {
+        return a;
+    };
+    let t = t.clamp(0.0, 1.0);
+    let lerp = |x: u8, y: u8| channel((f32::from(y) - f32::from(x)).mul_add(t, f32::from(x)));
+    map_rgb(a, |r1, g1, b1| (lerp(r1, r2), lerp(g1, g2), lerp(b1, b2)))
+}
+
+/// Round and clamp a floating-point channel value into `0..=255`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(crate) fn channel(value: f32) -> u8 {
+    // Clamped first, so the cast can neither truncate nor wrap.
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+/// Parse a `#rgb`, `#rrggbb` or `#rrggbbaa` hex string into RGB channels.
+///
+/// The leading `#` is optional and the alpha channel of the eight-digit form
+/// is ignored. This is the one hex parser behind every API that reads hex
+/// colors; [`parse_hex_color`] wraps it with an error. Usable in `const`
+/// contexts.
+pub(crate) const fn parse_hex(hex: &str) -> Option<(u8, u8, u8)> {
+    let bytes = hex.as_bytes();
+    let start = if !bytes.is_empty() && bytes[0] == b'#' {
+        1
+    } else {
+        0
+    };
+
+    match bytes.len() - start {
+        3 => match (
+            hex_digit(bytes[start]),
+            hex_digit(bytes[start + 1]),
+            hex_digit(bytes[start + 2]),
+        ) {
+            (Some(r), Some(g), Some(b)) => Some((r * 17, g * 17, b * 17)),
+            _ => None,
+        },
+        6 | 8 => {
+            // The alpha pair is checked too, so "#rrggbbzz" is rejected.
+            if bytes.len() - start == 8 && hex_byte(bytes, start + 6).is_none() {
+                return None;
+            }
+            match (
+                hex_byte(bytes, start),
+                hex_byte(bytes, start + 2),
+                hex_byte(bytes, start + 4),
+            ) {
+                (Some(r), Some(g), Some(b)) => Some((r, g, b)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Parse the two hex digits at `bytes[i..i + 2]` as one byte.
+const fn hex_byte(bytes: &[u8], i: usize) -> Option<u8> {
+    match (hex_digit(bytes[i]), hex_digit(bytes[i + 1])) {
+        (Some(hi), Some(lo)) => Some(hi << 4 | lo),
+        _ => None,
+    }
+}
+
+/// Value of a single ASCII hex digit.
+const fn hex_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Error returned by [`ThemePalette::from_hex`](crate::ThemePalette::from_hex).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexError {
+    /// The string contains a character that is not a hex digit.
+    InvalidDigit(char),
+    /// The number of hex digits is not 3, 6 or 8.
+    InvalidLength(usize),
+}
+
+impl core::fmt::Display for HexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidDigit(c) => write!(f, "Invalid hex digit {c:?}"),
+            Self::InvalidLength(len) => write!(
+                f,
+                "Invalid hex color length {len}, expected #rgb, #rrggbb or #rrggbbaa"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HexError {}
+
+/// Parse a hex color like [`parse_hex`], explaining why invalid input failed.
+pub(crate) fn parse_hex_color(hex: &str) -> Result<(u8, u8, u8), HexError> {
+    parse_hex(hex).ok_or_else(|| {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        digits.chars().find(|c| !c.is_ascii_hexdigit()).map_or(
+            HexError::InvalidLength(digits.len()),
+            HexError::InvalidDigit,
+        )
+    })
+}
+
+/// Format RGB channels as a lowercase `#rrggbb` string.
+pub(crate) fn hex_string((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+/// Convert RGB channels to HSL.
+///
+/// Returns hue in degrees (`0.0..360.0`) and saturation and lightness in
+/// `0.0..=1.0`. Grays have a hue of `0.0`.
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let (r, g, b) = (
+        f32::from(r) / 255.0,
+        f32::from(g) / 255.0,
+        f32::from(b) / 255.0,
+    );
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let delta = max - min;
+    if delta <= f32::EPSILON {
+        return (0.0, 0.0, l);
+    }
+
+    let s = delta / (1.0 - 2.0f32.mul_add(l, -1.0).abs());
+    let h = if (max - r).abs() <= f32::EPSILON {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if (max - g).abs() <= f32::EPSILON {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    (h * 60.0, s, l)
+}
+
+/// Convert HSL (hue in degrees, saturation and lightness in `0.0..=1.0`) to RGB.
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn hsl_to_rgb(h: f32, s: f32, l: f32) -> Color {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let c = (1.0 - 2.0f32.mul_add(l, -1.0).abs()) * s;
+    let x = c * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+    let m = l - c / 2.0;
+    let (r, g, b) = match h {
+        h if h < 1.0 => (c, x, 0.0),
+        h if h < 2.0 => (x, c, 0.0),
+        h if h < 3.0 => (0.0, c, x),
+        h if h < 4.0 => (0.0, x, c),
+        h if h < 5.0 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    Color::Rgb(
+        channel((r + m) * 255.0),
+        channel((g + m) * 255.0),
+        channel((b + m) * 255.0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lighten_and_darken_clamp_lightness() {
+        let black = Color::Rgb(0, 0, 0);
+        assert_eq!(black.lighten(0.5), Color::Rgb(128, 128, 128));
+        assert_eq!(black.lighten(2.0), Color::Rgb(255, 255, 255));
+        assert_eq!(black.darken(0.5), black);
+
+        let red = Color::Rgb(255, 0, 0);
+        assert_eq!(red.darken(0.25), Color::Rgb(128, 0, 0));
+        assert_eq!(red.lighten(0.25), Color::Rgb(255, 128, 128));
+    }
+
+    #[test]
+    fn test_best_text_color_prefers_earlier_on_ties() {
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        assert_eq!(best_text_color(white, &[white, black]), black);
+        assert_eq!(best_text_color(black, &[black, white, white]), white);
+        assert_eq!(best_text_color(Color::Reset, &[white, black]), white);
+        assert_eq!(best_text_color(black, &[]), Color::Reset);
+    }
+
+    #[test]
+    fn test_parse_hex_color_forms() {
+        assert_eq!(parse_hex_color("#bd93f9"), Ok((189, 147, 249)));
+        assert_eq!(parse_hex_color("BD93F9"), Ok((189, 147, 249)));
+        assert_eq!(parse_hex_color("#f0a"), Ok((255, 0, 170)));
+        assert_eq!(parse_hex_color("#bd93f980"), Ok((189, 147, 249)));
+        assert_eq!(parse_hex_color("#bd93f"), Err(HexError::InvalidLength(5)));
+        assert_eq!(parse_hex_color("#"), Err(HexError::InvalidLength(0)));
+        assert_eq!(parse_hex_color("#bd93fé"), Err(HexError::InvalidDigit('é')));
+        assert_eq!(parse_hex_color("##fff"), Err(HexError::InvalidDigit('#')));
+        assert_eq!(hex_string((189, 147, 249)), "#bd93f9");
+    }
+
+    #[test]
+    fn test_blend_endpoints_and_passthrough() {
+        let (from, to) = (Color::Rgb(10, 20, 30), Color::Rgb(110, 220, 30));
+        assert_eq!(from.blend(to, 0.0), from);
+        assert_eq!(from.blend(to, 1.0), to);
+        assert_eq!(from.blend(to, 0.5), Color::Rgb(60, 120, 30));
+        assert_eq!(from.blend(Color::White, 0.5), from);
+
+        for named in [Color::Blue, Color::Indexed(42), Color::Reset] {
+            assert_eq!(named.lighten(0.3), named);
+            assert_eq!(named.darken(0.3), named);
+            assert_eq!(named.blend(to, 0.5), named);
+        }
+    }
+}
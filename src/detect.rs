@@ -0,0 +1,219 @@
+//! Following the operating system's light/dark appearance.
+//!
+//! This module is available with the `detect` feature. It contains
+//! [`ThemeWatcher`], which polls the OS appearance and reports a theme only
+//! when the appearance actually changes.
+
+use crate::theme::ThemeName;
+
+/// Polls the OS light/dark appearance and yields a theme when it changes.
+///
+/// The watcher maps the appearance onto one of two preferred themes. Call
+/// [`poll`](Self::poll) from your event loop (e.g. once a second, or on focus
+/// gained); it returns `Some` only when the appearance differs from the last
+/// one seen, so you can switch themes and redraw exactly when needed. The
+/// first successful poll always yields the current preference.
+///
+/// Detection shells out to the platform's settings tool:
+///
+/// | Platform | Source |
+/// |----------|--------|
+/// | macOS | `defaults read -g AppleInterfaceStyle` |
+/// | Windows | the `AppsUseLightTheme` registry value |
+/// | Other Unix | `gsettings get org.gnome.desktop.interface color-scheme` |
+///
+/// If that fails, the terminal's `COLORFGBG` variable is consulted. When no
+/// source gives an answer, `poll` returns `None` and the current theme should
+/// be kept.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ratatui_themes::{Theme, ThemeName, ThemeWatcher};
+///
+/// let mut theme = Theme::new(ThemeName::Nord);
+/// let mut watcher = ThemeWatcher::new(ThemeName::Nord, ThemeName::SolarizedLight);
+///
+/// // In the event loop:
+/// if let Some(name) = watcher.poll() {
+///     theme = Theme::new(name);
+///     // redraw
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ThemeWatcher {
+    dark: ThemeName,
+    light: ThemeName,
+    last_light: Option<bool>,
+    detect: fn() -> Option<bool>,
+}
+
+impl ThemeWatcher {
+    /// Create a watcher that picks `dark` or `light` to match the OS.
+    #[must_use]
+    pub fn new(dark: ThemeName, light: ThemeName) -> Self {
+        Self {
+            dark,
+            light,
+            last_light: None,
+            detect: Self::system_is_light,
+        }
+    }
+
+    /// Replace the appearance source.
+    ///
+    /// `detect` returns `Some(true)` for light, `Some(false)` for dark and
+    /// `None` when the appearance is unknown. Use this to plug in another
+    /// source, such as a terminal background query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{ThemeName, ThemeWatcher};
+    ///
+    /// let mut watcher = ThemeWatcher::new(ThemeName::Nord, ThemeName::SolarizedLight)
+    ///     .with_detector(|| Some(true));
+    ///
+    /// assert_eq!(watcher.poll(), Some(ThemeName::SolarizedLight));
+    /// assert_eq!(watcher.poll(), None);
+    /// ```
+    #[must_use]
+    pub const fn with_detector(mut self, detect: fn() -> Option<bool>) -> Self {
+        self.detect = detect;
+        self
+    }
+
+    /// Check the appearance, returning the matching theme if it changed.
+    ///
+    /// Returns `None` if the appearance is unchanged since the last poll or
+    /// cannot be determined.
+    pub fn poll(&mut self) -> Option<ThemeName> {
+        let light = (self.detect)()?;
+        if self.last_light == Some(light) {
+            return None;
+        }
+        self.last_light = Some(light);
+        Some(if light { self.light } else { self.dark })
+    }
+
+    /// Returns the appearance seen by the last successful poll, if any.
+    ///
+    /// `Some(true)` means light.
+    #[must_use]
+    pub const fn last_light(&self) -> Option<bool> {
+        self.last_light
+    }
+
+    /// Ask the operating system whether it is in light mode.
+    ///
+    /// Returns `None` if the appearance cannot be determined. This runs an
+    /// external command, so avoid calling it every frame.
+    #[must_use]
+    pub fn system_is_light() -> Option<bool> {
+        query_os().or_else(|| {
+            std::env::var("COLORFGBG")
+                .ok()
+                .and_then(|v| colorfgbg_is_light(&v))
+        })
+    }
+}
+
+/// Run `program` with `args` and return its trimmed stdout.
+///
+/// Returns `None` if the program cannot be started. The exit status is
+/// ignored, because some tools signal "unset" through it.
+#[cfg(any(unix, windows))]
+fn output(program: &str, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .ok()?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(target_os = "macos")]
+fn query_os() -> Option<bool> {
+    // The key only exists in dark mode; reading it fails otherwise.
+    let style = output("defaults", &["read", "-g", "AppleInterfaceStyle"])?;
+    Some(!style.eq_ignore_ascii_case("dark"))
+}
+
+#[cfg(windows)]
+fn query_os() -> Option<bool> {
+    let value = output(
+        "reg",
+        &[
+            "query",
+            r"HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize",
+            "/v",
+            "AppsUseLightTheme",
+        ],
+    )?;
+    if value.ends_with("0x1") {
+        Some(true)
+    } else if value.ends_with("0x0") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn query_os() -> Option<bool> {
+    let scheme = output(
+        "gsettings",
+        &["get", "org.gnome.desktop.interface", "color-scheme"],
+    )?;
+    match scheme.trim_matches('\'') {
+        "prefer-dark" => Some(false),
+        "prefer-light" | "default" => Some(true),
+        _ => None,
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn query_os() -> Option<bool> {
+    None
+}
+
+/// Interpret a `COLORFGBG` value such as `"15;0"` (foreground;background).
+///
+/// The background is an ANSI color index: 7 (white) and 9–15 (bright
+/// colors, except 8 which is dark gray) count as light.
+fn colorfgbg_is_light(value: &str) -> Option<bool> {
+    let bg: u8 = value.rsplit(';').next()?.parse().ok()?;
+    Some(bg == 7 || (9..=15).contains(&bg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_reports_only_changes() {
+        let mut watcher = ThemeWatcher::new(ThemeName::Kanagawa, ThemeName::CatppuccinLatte)
+            .with_detector(|| Some(false));
+        assert_eq!(watcher.last_light(), None);
+        assert_eq!(watcher.poll(), Some(ThemeName::Kanagawa));
+        assert_eq!(watcher.poll(), None);
+        assert_eq!(watcher.last_light(), Some(false));
+
+        watcher = watcher.with_detector(|| Some(true));
+        assert_eq!(watcher.poll(), Some(ThemeName::CatppuccinLatte));
+        assert_eq!(watcher.poll(), None);
+
+        // An unknown appearance keeps the last one.
+        watcher = watcher.with_detector(|| None);
+        assert_eq!(watcher.poll(), None);
+        assert_eq!(watcher.last_light(), Some(true));
+    }
+
+    #[test]
+    fn test_colorfgbg() {
+        assert_eq!(colorfgbg_is_light("15;0"), Some(false));
+        assert_eq!(colorfgbg_is_light("0;15"), Some(true));
+        assert_eq!(colorfgbg_is_light("0;default;7"), Some(true));
+        assert_eq!(colorfgbg_is_light("7;8"), Some(false));
+        assert_eq!(colorfgbg_is_light("default"), None);
+    }
+}
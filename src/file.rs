@@ -0,0 +1,213 @@
+//! Loading palettes and themes from files on disk.
+//!
+//! This module is available with the `fs` feature. Files may be TOML or JSON;
+//! the format is chosen by extension (`.json` is JSON, anything else TOML).
+
+use crate::palette::ThemePalette;
+use crate::theme::Theme;
+use serde::de::DeserializeOwned;
+use std::{fs, io, path::Path};
+
+/// Returns `true` if `path` should be parsed as JSON rather than TOML.
+fn is_json(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+}
+
+/// Read `path` and deserialize it as TOML, or as JSON for `.json` files.
+///
+/// Parse failures are reported as [`io::ErrorKind::InvalidData`].
+fn read<T: DeserializeOwned>(path: &Path) -> io::Result<T> {
+    let contents = fs::read_to_string(path)?;
+    if is_json(path) {
+        serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    } else {
+        toml::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl ThemePalette {
+    /// Load a palette from a TOML or JSON theme file.
+    ///
+    /// The file must set all ten palette fields to `"#rrggbb"` strings (or
+    /// any other form [`from_hex()`](Self::from_hex) accepts):
+    ///
+    /// ```toml
+    /// accent = "#bd93f9"
+    /// secondary = "#ff79c6"
+    /// bg = "#282a36"
+    /// fg = "#f8f8f2"
+    /// muted = "#6272a4"
+    /// selection = "#44475a"
+    /// error = "#ff5555"
+    /// warning = "#ffb86c"
+    /// success = "#50fa7b"
+    /// info = "#8be9fd"
+    /// ```
+    ///
+    /// Files ending in `.json` are parsed as JSON with the same keys; anything
+    /// else is parsed as TOML. Unknown keys are ignored, so theme files
+    /// written for a newer version of this crate still load.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying I/O error if the file cannot be read. A file
+    /// that is not valid TOML/JSON, misses a field, or contains a malformed
+    /// color yields an error of kind [`io::ErrorKind::InvalidData`] wrapping
+    /// the [`ThemeParseError`](crate::ThemeParseError) from
+    /// [`from_toml_str()`](Self::from_toml_str) or
+    /// [`from_json_str()`](Self::from_json_str).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::path::Path;
+    /// use ratatui_themes::ThemePalette;
+    ///
+    /// let palette = ThemePalette::from_file(Path::new("my-theme.toml"))?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn from_file(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let palette = if is_json(path) {
+            Self::from_json_str(&contents)
+        } else {
+            Self::from_toml_str(&contents)
+        };
+        palette.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl Theme {
+    /// Load a theme selection from a TOML or JSON file.
+    ///
+    /// A TOML file is a table with a `name` key, e.g. `name = "tokyo-night"`.
+    /// A JSON file is either `{"name": "tokyo-night"}` or the bare string
+    /// `"tokyo-night"`. Any spelling [`ThemeName`](crate::ThemeName) parses
+    /// is accepted. Use [`ThemePalette::from_file`] for files that define
+    /// colors.
+    ///
+    /// Note that [`Theme`] serializes as a bare slug string, not as a table.
+    /// Its serialized form can be written to a JSON file as is, but a TOML
+    /// document cannot be a bare string, so when saving to TOML put the
+    /// theme under a `name` key (e.g. in a struct with a `name: Theme` field).
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying I/O error if the file cannot be read, or an
+    /// error of kind [`io::ErrorKind::InvalidData`] if it cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ratatui_themes::Theme;
+    ///
+    /// let theme = Theme::load("theme.toml")?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        read(path.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ThemeName;
+    use ratatui::style::Color;
+    use std::path::PathBuf;
+
+    fn write_temp(name: &str, contents: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("ratatui-themes-{}-{name}", std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    const DRACULA_TOML: &str = r##"
+        accent = "#bd93f9"
+        secondary = "#ff79c6"
+        bg = "#282a36"
+        fg = "#f8f8f2"
+        muted = "#6272a4"
+        selection = "#44475a"
+        error = "#ff5555"
+        warning = "#ffb86c"
+        success = "#50fa7b"
+        info = "#8be9fd"
+    "##;
+
+    #[test]
+    fn test_palette_from_toml_and_json() {
+        let toml_path = write_temp("palette.toml", DRACULA_TOML);
+        let palette = ThemePalette::from_file(&toml_path).unwrap();
+        assert_eq!(palette.matches_builtin(), Some(ThemeName::Dracula));
+
+        let json = r##"{"accent": "#ff0000", "secondary": "#ff79c6", "bg": "#282a36",
+            "fg": "#f8f8f2", "muted": "#6272a4", "selection": "#44475a",
+            "error": "#ff5555", "warning": "#ffb86c", "success": "#50fa7b",
+            "info": "#8be9fd"}"##;
+        let json_path = write_temp("palette.json", json);
+        let palette = ThemePalette::from_file(&json_path).unwrap();
+        assert_eq!(palette.accent, Color::Rgb(255, 0, 0));
+
+        fs::remove_file(toml_path).unwrap();
+        fs::remove_file(json_path).unwrap();
+    }
+
+    #[test]
+    fn test_errors_distinguish_io_from_parse() {
+        let missing = ThemePalette::from_file(Path::new("/nonexistent/theme.toml")).unwrap_err();
+        assert_eq!(missing.kind(), io::ErrorKind::NotFound);
+
+        let path = write_temp("bad.toml", &DRACULA_TOML.replace("#bd93f9", "purple"));
+        let bad = ThemePalette::from_file(&path).unwrap_err();
+        assert_eq!(bad.kind(), io::ErrorKind::InvalidData);
+        assert!(bad.to_string().contains("invalid hex color"));
+        fs::remove_file(path).unwrap();
+
+        let path = write_temp("partial.json", r##"{"accent": "#bd93f9"}"##);
+        let partial = ThemePalette::from_file(&path).unwrap_err();
+        assert_eq!(partial.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            partial.to_string(),
+            "Theme is missing the `secondary` color"
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_toml_ignores_unknown_keys() {
+        let path = write_temp(
+            "future.toml",
+            &format!("{DRACULA_TOML}\nborder = \"#6272a4\""),
+        );
+        let palette = ThemePalette::from_file(&path).unwrap();
+        assert_eq!(palette, ThemeName::Dracula.palette());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_theme_load() {
+        let path = write_temp("theme.toml", r#"name = "tokyo-night""#);
+        assert_eq!(Theme::load(&path).unwrap().name, ThemeName::TokyoNight);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_theme_save_load_round_trip() {
+        for &name in ThemeName::all() {
+            let theme = Theme::new(name);
+            let saved = serde_json::to_string(&theme).unwrap();
+
+            let json_path = write_temp("saved.json", &saved);
+            assert_eq!(Theme::load(&json_path).unwrap(), theme);
+            fs::remove_file(json_path).unwrap();
+
+            // A quoted slug is a valid TOML string too.
+            let toml_path = write_temp("saved.toml", &format!("name = {saved}"));
+            assert_eq!(Theme::load(&toml_path).unwrap(), theme);
+            fs::remove_file(toml_path).unwrap();
+        }
+    }
+}
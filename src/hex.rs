@@ -0,0 +1,149 @@
+//! Hex-string serde representation of palettes.
+//!
+//! Serialized palettes store each color as a `"#rrggbb"` string, and also
+//! accept the `"#rgb"` and `"#rrggbbaa"` forms when reading. This module
+//! holds the private serde types for that format, used by the `Serialize` and
+//! `Deserialize` impls of [`ThemePalette`] and shared by the `fs` and `json`
+//! features.
+
+use crate::color::{hex_string, parse_hex, to_rgb};
+use crate::palette::ThemePalette;
+#[cfg(feature = "loader")]
+use crate::{builder::ThemePaletteBuilder, palette::PaletteValidationError};
+use ratatui::style::Color;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A color written as `"#rrggbb"`.
+///
+/// Reading accepts every form [`ThemePalette::from_hex`] does. Named and indexed colors serialize as their resolved RGB value.
+/// [`Color::Reset`] has none and is written (and read back) as `"reset"`.
+pub(crate) struct HexColor(pub(crate) Color);
+
+impl Serialize for HexColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match to_rgb(self.0) {
+            Some(rgb) => serializer.serialize_str(&hex_string(rgb)),
+            None => serializer.serialize_str("reset"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for HexColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        if hex.eq_ignore_ascii_case("reset") {
+            return Ok(Self(Color::Reset));
+        }
+        parse_hex(&hex)
+            .map(|(r, g, b)| Self(Color::Rgb(r, g, b)))
+            .ok_or_else(|| {
+                log_fallback!("invalid hex color {hex:?} in serialized palette");
+                serde::de::Error::custom(format!(
+                    "invalid hex color {hex:?}, expected \"#rgb\", \"#rrggbb\" or \"#rrggbbaa\""
+                ))
+            })
+    }
+}
+
+/// A palette as an object of field name to hex string.
+///
+/// The ten original fields are required. Unknown fields are ignored, so files
+/// written by a newer version with extra palette fields still load; any field
+/// added here later must be `#[serde(default)]` for the same reason, so older
+/// files keep loading too.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct HexPalette {
+    accent: HexColor,
+    secondary: HexColor,
+    bg: HexColor,
+    fg: HexColor,
+    muted: HexColor,
+    selection: HexColor,
+    error: HexColor,
+    warning: HexColor,
+    success: HexColor,
+    info: HexColor,
+}
+
+impl From<HexPalette> for ThemePalette {
+    fn from(hex: HexPalette) -> Self {
+        Self {
+            accent: hex.accent.0,
+            secondary: hex.secondary.0,
+            bg: hex.bg.0,
+            fg: hex.fg.0,
+            muted: hex.muted.0,
+            selection: hex.selection.0,
+            error: hex.error.0,
+            warning: hex.warning.0,
+            success: hex.success.0,
+            info: hex.info.0,
+        }
+    }
+}
+
+/// A palette whose fields may be missing, for reporting which one is.
+#[cfg(feature = "loader")]
+#[derive(Deserialize)]
+pub(crate) struct PartialHexPalette {
+    accent: Option<HexColor>,
+    secondary: Option<HexColor>,
+    bg: Option<HexColor>,
+    fg: Option<HexColor>,
+    muted: Option<HexColor>,
+    selection: Option<HexColor>,
+    error: Option<HexColor>,
+    warning: Option<HexColor>,
+    success: Option<HexColor>,
+    info: Option<HexColor>,
+}
+
+#[cfg(feature = "loader")]
+impl PartialHexPalette {
+    /// Returns the palette, checking for missing fields with
+    /// [`ThemePaletteBuilder::build`].
+    pub(crate) fn complete(self) -> Result<ThemePalette, PaletteValidationError> {
+        ThemePaletteBuilder {
+            accent: self.accent.map(|c| c.0),
+            secondary: self.secondary.map(|c| c.0),
+            bg: self.bg.map(|c| c.0),
+            fg: self.fg.map(|c| c.0),
+            muted: self.muted.map(|c| c.0),
+            selection: self.selection.map(|c| c.0),
+            error: self.error.map(|c| c.0),
+            warning: self.warning.map(|c| c.0),
+            success: self.success.map(|c| c.0),
+            info: self.info.map(|c| c.0),
+        }
+        .build()
+    }
+}
+
+impl Serialize for ThemePalette {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        HexPalette::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ThemePalette {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        HexPalette::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl From<&ThemePalette> for HexPalette {
+    fn from(palette: &ThemePalette) -> Self {
+        Self {
+            accent: HexColor(palette.accent),
+            secondary: HexColor(palette.secondary),
+            bg: HexColor(palette.bg),
+            fg: HexColor(palette.fg),
+            muted: HexColor(palette.muted),
+            selection: HexColor(palette.selection),
+            error: HexColor(palette.error),
+            warning: HexColor(palette.warning),
+            success: HexColor(palette.success),
+            info: HexColor(palette.info),
+        }
+    }
+}
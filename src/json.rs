@@ -0,0 +1,117 @@
+//! JSON import and export of palettes.
+//!
+//! This module is available with the `json` feature. Palettes are written as
+//! an object mapping each field name to a `"#rrggbb"` string.
+
+use crate::hex::HexPalette;
+use crate::palette::ThemePalette;
+
+impl ThemePalette {
+    /// Serialize the palette as pretty-printed JSON.
+    ///
+    /// The output is an object of field name to `"#rrggbb"` string. RGB
+    /// colors round-trip exactly through [`from_json()`](Self::from_json).
+    /// Named and indexed colors are written as their resolved RGB value, and
+    /// [`Color::Reset`](ratatui::style::Color::Reset) as `"reset"`.
+    ///
+    /// # Panics
+    ///
+    /// Never; serializing a palette cannot fail.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let json = ThemeName::Dracula.palette().to_json();
+    /// assert!(json.contains(r##""accent": "#bd93f9""##));
+    /// ```
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&HexPalette::from(self))
+            .expect("a palette always serializes to JSON")
+    }
+
+    /// Parse a palette from JSON produced by [`to_json()`](Self::to_json).
+    ///
+    /// All ten fields are required. Unknown fields are ignored, so JSON
+    /// written by a future version with additional palette fields still
+    /// loads.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not valid JSON, misses a field, or
+    /// contains a color that is neither a hex string accepted by
+    /// [`from_hex()`](Self::from_hex) nor `"reset"`. With the `loader`
+    /// feature, `ThemePalette::from_json_str` reads the same format and
+    /// names the missing field in a `ThemeParseError::MissingField` instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{ThemeName, ThemePalette};
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    /// assert_eq!(ThemePalette::from_json(&palette.to_json())?, palette);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str::<HexPalette>(json).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ThemeName;
+    use ratatui::style::Color;
+
+    #[test]
+    fn test_json_round_trips_every_builtin() {
+        for theme in ThemeName::all() {
+            let palette = theme.palette();
+            assert_eq!(
+                ThemePalette::from_json(&palette.to_json()).unwrap(),
+                palette
+            );
+        }
+    }
+
+    #[test]
+    fn test_json_shape_and_errors() {
+        let palette = ThemePalette {
+            bg: Color::Reset,
+            ..ThemeName::Nord.palette()
+        };
+        let value: serde_json::Value = serde_json::from_str(&palette.to_json()).unwrap();
+        assert_eq!(value["bg"], "reset");
+        assert_eq!(value["accent"], "#88c0d0");
+        assert_eq!(
+            ThemePalette::from_json(&palette.to_json()).unwrap(),
+            palette
+        );
+
+        // Unlike `ThemePalette::to_hex`, the file format resolves named colors.
+        let named = ThemePalette {
+            fg: Color::White,
+            ..palette
+        };
+        let value: serde_json::Value = serde_json::from_str(&named.to_json()).unwrap();
+        assert_eq!(value["fg"], "#ffffff");
+
+        assert!(ThemePalette::from_json(r##"{"accent": "#88c0d0"}"##).is_err());
+    }
+
+    #[test]
+    fn test_json_ignores_unknown_fields() {
+        let palette = ThemeName::Kanagawa.palette();
+        let mut value: serde_json::Value = serde_json::from_str(&palette.to_json()).unwrap();
+        value["border"] = "#54546d".into();
+        value["cursor"] = serde_json::json!({ "fg": "#000000", "bg": "#ffffff" });
+
+        assert_eq!(
+            ThemePalette::from_json(&value.to_string()).unwrap(),
+            palette
+        );
+    }
+}
@@ -59,6 +59,8 @@
 //! Save and load theme preferences (requires the `serde` feature, enabled by default):
 //!
 //! ```rust
+//! # #[cfg(feature = "serde")]
+//! # mod config {
 //! use ratatui_themes::ThemeName;
 //! use serde::{Deserialize, Serialize};
 //!
@@ -70,6 +72,21 @@
 //!
 //! // Theme names serialize as kebab-case strings:
 //! // { "theme": "tokyo-night" }
+//! # }
+//! ```
+//!
+//! ## Custom Palettes
+//!
+//! The [`palette!`] macro builds a [`ThemePalette`] from hex strings, filling
+//! any field you leave out from Dracula:
+//!
+//! ```rust
+//! use ratatui_themes::{palette, ThemePalette};
+//!
+//! const BRAND: ThemePalette = palette! {
+//!     accent: "#ff5f00",
+//!     secondary: "#5f87ff",
+//! };
 //! ```
 //!
 //! ## Available Themes
@@ -94,15 +111,40 @@
 //!
 //! ## Feature Flags
 //!
-//! - **`serde`** (enabled by default) — Enables serialization/deserialization of theme names
+//! - **`std`** (enabled by default) — Standard library support; everything that reads the environment or files needs it. Without it the crate is `no_std` (it still needs `alloc`) and requires `libm`
+//! - **`libm`** — Floating-point math for `no_std` builds
+//! - **`serde`** (enabled by default) — Enables serialization/deserialization of theme names; implies `std`
+//! - **`widgets`** (enabled by default) — Ready-made widgets such as [`ThemePicker`]
+//! - **`json`** — [`ThemePalette::to_json`] and [`ThemePalette::from_json`] using hex color strings
+//! - **`loader`** — Parse palettes from TOML/JSON strings ([`ThemePalette::from_toml_str`], [`ThemePalette::from_json_str`]); implies `json`
+//! - **`fs`** — Load palettes and themes from TOML/JSON files ([`ThemePalette::from_file`], [`Theme::load`]); implies `loader`
+//! - **`detect`** — [`ThemeWatcher`], which follows the OS light/dark appearance
+//! - **`log`** — Emits `log::warn!` records when the crate falls back instead of failing
+//!
+//! With `log` enabled, the following events are reported:
+//!
+//! - An unknown theme name is passed to [`ThemeName::from_str`](core::str::FromStr::from_str)
+//! - [`ThemeResolver`] skips a CLI or environment value that is not a known theme
+//! - [`ThemeName::parse_list`] (and so [`ThemeName::parse_env`]) skips an unknown entry
+//! - [`ThemePalette::from_hex`] (and so [`ThemePalette::from_base16_hex`]) is given an invalid hex color
+//! - A serialized palette (serde, JSON, TOML or a theme file) contains an invalid hex color
 //!
 //! To disable serde support:
 //!
 //! ```toml
 //! [dependencies]
-//! ratatui-themes = { version = "0.1", default-features = false }
+//! ratatui-themes = { version = "0.1", default-features = false, features = ["std"] }
+//! ```
+//!
+//! For `no_std` targets, the theme names, palettes and color helpers work
+//! with only `alloc`, using `libm` for floating-point math:
+//!
+//! ```toml
+//! [dependencies]
+//! ratatui-themes = { version = "0.1", default-features = false, features = ["libm"] }
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![doc(html_root_url = "https://docs.rs/ratatui-themes/0.1.1")]
 #![warn(
     missing_docs,
@@ -112,13 +154,73 @@
     clippy::all,
     clippy::pedantic
 )]
-#![allow(clippy::module_name_repetitions)]
+#![allow(clippy::module_name_repetitions, clippy::redundant_pub_crate)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!(
+    "ratatui-themes needs either the `std` or the `libm` feature for floating-point math"
+);
 
+extern crate alloc;
+
+/// Report a fallback event through `log::warn!` when the `log` feature is enabled.
+///
+/// Expands to nothing without the feature, so call sites cost nothing.
+macro_rules! log_fallback {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "log")]
+        ::log::warn!(target: "ratatui_themes", $($arg)+);
+    };
+}
+
+mod builder;
+mod cache;
+mod color;
+#[cfg(feature = "detect")]
+mod detect;
+#[cfg(feature = "fs")]
+mod file;
+#[cfg(feature = "serde")]
+mod hex;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "loader")]
+mod loader;
+mod macros;
+mod math;
+mod names;
 mod palette;
+mod pipeline;
+#[cfg(feature = "std")]
+mod resolve;
+mod set;
 mod theme;
+#[cfg(feature = "widgets")]
+mod widgets;
+
+#[doc(hidden)]
+pub use macros::__private;
 
-pub use palette::ThemePalette;
-pub use theme::{Theme, ThemeName};
+pub use builder::ThemePaletteBuilder;
+pub use cache::ThemeStyleCache;
+pub use color::{best_text_color, ColorExt, ContrastGrade, HexError};
+#[cfg(feature = "detect")]
+pub use detect::ThemeWatcher;
+#[cfg(feature = "loader")]
+pub use loader::ThemeParseError;
+pub use palette::{NotificationLevel, PaletteValidationError, ThemePalette};
+pub use pipeline::{EInk, PaletteAdapter, PalettePipeline, SoftenExtremes, Tint};
+#[cfg(feature = "std")]
+pub use resolve::{ThemeResolver, ThemeSource};
+pub use set::ThemeSet;
+pub use theme::{
+    DisplayBrightness, Theme, ThemeMetadata, ThemeMode, ThemeName, ThemeNameParseError,
+    UnknownThemeId, PALETTES,
+};
+#[cfg(feature = "widgets")]
+pub use widgets::{
+    CursorShape, GradientLine, Legend, ThemeDemo, ThemeList, ThemeListState, ThemePicker,
+};
 
 /// Re-export ratatui's [`Color`] type for convenience.
 ///
@@ -0,0 +1,183 @@
+//! Parsing palettes from TOML and JSON documents.
+//!
+//! This module is available with the `loader` feature. It contains
+//! [`ThemeParseError`] and the `ThemePalette::from_toml_str` and
+//! `ThemePalette::from_json_str` constructors, which read the same
+//! `"#rrggbb"` format as the palette's serde representation.
+
+use crate::hex::PartialHexPalette;
+use crate::palette::{PaletteValidationError, ThemePalette};
+
+/// Error returned when a theme document cannot be turned into a palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ThemeParseError {
+    /// The document is not valid TOML/JSON, or a color is neither a hex
+    /// string accepted by `ThemePalette::from_hex` nor `"reset"`. Holds the parser's message.
+    Syntax(String),
+    /// A palette color is not set.
+    MissingField {
+        /// Name of the first missing field, in field order.
+        field: &'static str,
+    },
+}
+
+impl std::fmt::Display for ThemeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax(message) => write!(f, "Invalid theme: {message}"),
+            Self::MissingField { field } => write!(f, "Theme is missing the `{field}` color"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeParseError {}
+
+impl ThemePalette {
+    /// Parse a palette from a TOML document.
+    ///
+    /// The document sets each of the ten palette fields to a `"#rrggbb"`
+    /// string (`"#rgb"`, `"#rrggbbaa"` and `"reset"` are accepted too), as in `examples/custom-theme.toml`:
+    ///
+    /// ```toml
+    /// accent = "#e0af68"
+    /// secondary = "#bb9af7"
+    /// bg = "#1f2335"
+    /// # ... fg, muted, selection, error, warning, success, info
+    /// ```
+    ///
+    /// Unknown keys are ignored, so documents written for a newer version of
+    /// this crate still load.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeParseError::MissingField`] naming the first color that
+    /// is not set, or [`ThemeParseError::Syntax`] if the document is not
+    /// valid TOML or contains a malformed color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{ThemeName, ThemePalette, ThemeParseError};
+    ///
+    /// let toml = r##"
+    ///     accent = "#bd93f9"
+    ///     secondary = "#ff79c6"
+    ///     bg = "#282a36"
+    ///     fg = "#f8f8f2"
+    ///     muted = "#6272a4"
+    ///     selection = "#44475a"
+    ///     error = "#ff5555"
+    ///     warning = "#ffb86c"
+    ///     success = "#50fa7b"
+    ///     info = "#8be9fd"
+    /// "##;
+    /// assert_eq!(ThemePalette::from_toml_str(toml)?, ThemeName::Dracula.palette());
+    ///
+    /// let err = ThemePalette::from_toml_str(r##"accent = "#bd93f9""##).unwrap_err();
+    /// assert_eq!(err, ThemeParseError::MissingField { field: "secondary" });
+    /// # Ok::<(), ThemeParseError>(())
+    /// ```
+    pub fn from_toml_str(toml: &str) -> Result<Self, ThemeParseError> {
+        toml::from_str::<PartialHexPalette>(toml)
+            .map_err(|e| ThemeParseError::Syntax(e.message().to_string()))
+            .and_then(complete)
+    }
+
+    /// Parse a palette from a JSON document.
+    ///
+    /// Like [`from_toml_str()`](Self::from_toml_str), for an object with the
+    /// same keys. Unlike [`from_json()`](Self::from_json), errors are
+    /// reported as [`ThemeParseError`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeParseError::MissingField`] naming the first color that
+    /// is not set, or [`ThemeParseError::Syntax`] if the document is not
+    /// valid JSON or contains a malformed color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{ThemeName, ThemePalette, ThemeParseError};
+    ///
+    /// let json = ThemeName::Nord.palette().to_json();
+    /// assert_eq!(ThemePalette::from_json_str(&json)?, ThemeName::Nord.palette());
+    ///
+    /// let err = ThemePalette::from_json_str(r##"{"accent": "#88c0d0"}"##).unwrap_err();
+    /// assert_eq!(err.to_string(), "Theme is missing the `secondary` color");
+    /// # Ok::<(), ThemeParseError>(())
+    /// ```
+    pub fn from_json_str(json: &str) -> Result<Self, ThemeParseError> {
+        serde_json::from_str::<PartialHexPalette>(json)
+            .map_err(|e| ThemeParseError::Syntax(e.to_string()))
+            .and_then(complete)
+    }
+}
+
+/// Turn a parsed document into a palette, reporting the first missing field.
+fn complete(partial: PartialHexPalette) -> Result<ThemePalette, ThemeParseError> {
+    partial.complete().map_err(|err| match err {
+        PaletteValidationError::MissingField { field } => ThemeParseError::MissingField { field },
+        // `build()` checks only that every color is set.
+        _ => unreachable!("unexpected palette error: {err}"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+
+    const SAMPLE: &str = include_str!("../examples/custom-theme.toml");
+
+    #[test]
+    fn test_sample_file_parses() {
+        let palette = ThemePalette::from_toml_str(SAMPLE).unwrap();
+        assert_eq!(palette.accent, Color::Rgb(0xe0, 0xaf, 0x68));
+        assert_eq!(palette.info, Color::Rgb(0x7d, 0xcf, 0xff));
+        assert_eq!(palette.validate(), Ok(()));
+
+        assert_eq!(ThemePalette::from_json_str(&palette.to_json()), Ok(palette));
+    }
+
+    #[test]
+    fn test_errors_name_the_missing_field() {
+        let without_bg: String = SAMPLE
+            .lines()
+            .filter(|line| !line.starts_with("bg"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(
+            ThemePalette::from_toml_str(&without_bg),
+            Err(ThemeParseError::MissingField { field: "bg" })
+        );
+
+        let bad = SAMPLE.replace("#e0af68", "orange");
+        let Err(ThemeParseError::Syntax(message)) = ThemePalette::from_toml_str(&bad) else {
+            panic!("expected a syntax error");
+        };
+        assert!(message.contains("invalid hex color"), "{message}");
+
+        assert!(matches!(
+            ThemePalette::from_json_str("{"),
+            Err(ThemeParseError::Syntax(_))
+        ));
+    }
+
+    #[test]
+    fn test_missing_field_matches_builder() {
+        let builder = ThemePalette::builder().accent(Color::Rgb(0xe0, 0xaf, 0x68));
+        let Err(PaletteValidationError::MissingField { field }) = builder.build() else {
+            panic!("expected a missing field");
+        };
+        assert_eq!(
+            ThemePalette::from_json_str(r##"{"accent": "#e0af68"}"##),
+            Err(ThemeParseError::MissingField { field })
+        );
+        assert_eq!(
+            ThemePalette::from_toml_str(""),
+            Err(ThemeParseError::MissingField { field: "accent" })
+        );
+    }
+}
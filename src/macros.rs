@@ -0,0 +1,152 @@
+//! Declarative macros for defining palettes and per-theme data in code.
+
+/// Define a [`ThemePalette`](crate::ThemePalette) from hex color strings.
+///
+/// Each entry is a palette field name followed by a `"#rrggbb"` string literal
+/// (`"#rgb"` and `"#rrggbbaa"` work too, as in [`ThemePalette::from_hex`](crate::ThemePalette::from_hex)).
+/// Fields that are not listed default to Dracula's colors. The expansion is a
+/// constant expression, so it can also initialize a `const` or `static`.
+///
+/// Mistakes are reported at compile time: an unknown field name is rejected
+/// by the struct literal, and a malformed hex string fails constant
+/// evaluation with an "invalid hex color" message.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::style::Color;
+/// use ratatui_themes::{palette, ThemeName, ThemePalette};
+///
+/// const MINE: ThemePalette = palette! {
+///     accent: "#ff79c6",
+///     bg: "#1e1f29",
+/// };
+///
+/// assert_eq!(MINE.accent, Color::Rgb(0xff, 0x79, 0xc6));
+/// assert_eq!(MINE.bg, Color::Rgb(0x1e, 0x1f, 0x29));
+/// assert_eq!(MINE.fg, ThemeName::Dracula.palette().fg);
+/// ```
+///
+/// Invalid input does not compile:
+///
+/// ```rust,compile_fail
+/// # use ratatui_themes::palette;
+/// let _ = palette! { acent: "#ff79c6" };
+/// ```
+///
+/// ```rust,compile_fail
+/// # use ratatui_themes::palette;
+/// let _ = palette! { accent: "#ff79c" };
+/// ```
+#[macro_export]
+macro_rules! palette {
+    ($($field:ident : $hex:literal),* $(,)?) => {{
+        #[allow(clippy::needless_update)]
+        const PALETTE: $crate::ThemePalette = $crate::ThemePalette {
+            $($field: $crate::__private::hex($hex),)*
+            ..$crate::ThemeName::Dracula.palette()
+        };
+        PALETTE
+    }};
+}
+
+/// Build a `[T; ThemeName::COUNT]` table of per-theme data.
+///
+/// Each entry names a [`ThemeName`](crate::ThemeName) variant and its value.
+/// Entries must list every theme exactly once in [`ThemeName::all()`]
+/// order; anything else fails to compile with a constant-evaluation error.
+/// Read entries back with
+/// [`ThemeName::lookup()`](crate::ThemeName::lookup). Adding a per-theme
+/// property is then one table instead of a new `match`, and adding a theme
+/// without updating a table does not compile.
+///
+/// [`ThemeName::all()`]: crate::ThemeName::all
+macro_rules! theme_table {
+    ($($theme:ident => $value:expr),* $(,)?) => {{
+        const _: () = $crate::macros::check_theme_order(&[$($crate::ThemeName::$theme),*]);
+        [$($value),*]
+    }};
+}
+
+pub(crate) use theme_table;
+
+/// Panic unless `themes` is exactly [`ThemeName::all()`](crate::ThemeName::all), in order.
+pub(crate) const fn check_theme_order(themes: &[crate::ThemeName]) {
+    let all = crate::ThemeName::all();
+    assert!(
+        themes.len() == all.len(),
+        "theme table must have one entry per theme"
+    );
+    let mut i = 0;
+    while i < all.len() {
+        assert!(
+            themes[i] as usize == all[i] as usize,
+            "theme table entries must follow ThemeName::all() order"
+        );
+        i += 1;
+    }
+}
+
+/// Support items for the exported macros. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use crate::color::parse_hex;
+    use ratatui::style::Color;
+
+    /// Parse a hex literal for [`palette!`], failing const evaluation on bad input.
+    #[must_use]
+    pub const fn hex(hex: &str) -> Color {
+        match parse_hex(hex) {
+            Some((r, g, b)) => Color::Rgb(r, g, b),
+            None => panic!("invalid hex color: expected \"#rgb\", \"#rrggbb\" or \"#rrggbbaa\""),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ThemeName, ThemePalette};
+    use ratatui::style::Color;
+
+    #[test]
+    fn test_palette_macro_overrides_and_defaults() {
+        let palette: ThemePalette = palette! { accent: "#BD93F9", error: "ff0000" };
+        let dracula = ThemeName::Dracula.palette();
+
+        assert_eq!(palette.accent, Color::Rgb(0xbd, 0x93, 0xf9));
+        assert_eq!(palette.error, Color::Rgb(0xff, 0, 0));
+        assert_eq!(palette.bg, dracula.bg);
+        assert_eq!(palette! {}, dracula);
+    }
+
+    #[test]
+    fn test_theme_table_order_check() {
+        super::check_theme_order(ThemeName::all());
+    }
+
+    #[test]
+    #[should_panic(expected = "ThemeName::all() order")]
+    fn test_theme_table_rejects_reordered_entries() {
+        let mut themes = ThemeName::all().to_vec();
+        themes.swap(0, 1);
+        super::check_theme_order(&themes);
+    }
+
+    #[test]
+    #[should_panic(expected = "one entry per theme")]
+    fn test_theme_table_rejects_missing_entries() {
+        super::check_theme_order(&ThemeName::all()[1..]);
+    }
+
+    #[test]
+    fn test_parse_hex_rejects_malformed_input() {
+        use crate::color::parse_hex;
+
+        assert_eq!(parse_hex("#0a0B0c"), Some((10, 11, 12)));
+        assert_eq!(parse_hex("#fff"), Some((255, 255, 255)));
+        assert_eq!(parse_hex("#ffff"), None);
+        assert_eq!(parse_hex("#gggggg"), None);
+        assert_eq!(parse_hex("#0a0b0cgg"), None);
+        assert_eq!(parse_hex(""), None);
+    }
+}
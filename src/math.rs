@@ -0,0 +1,55 @@
+//! Floating-point functions for `no_std` builds.
+//!
+//! With the `std` feature, the inherent `f32`/`f64` methods are used and this
+//! module is empty. Without it, [`Float`] provides the same methods through
+//! `libm`; modules doing float math import it under
+//! `#[cfg(not(any(feature = "std", test)))]` so call sites read the same
+//! either way. Test builds always link `std` for the test harness, whose
+//! inherent methods take precedence, so the trait is left out there.
+
+/// The `f32`/`f64` methods missing from `core`, implemented with `libm`.
+#[cfg(not(any(feature = "std", test)))]
+pub(crate) trait Float: Sized {
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn round(self) -> Self;
+    fn sqrt(self) -> Self;
+}
+
+#[cfg(not(any(feature = "std", test)))]
+macro_rules! impl_float {
+    ($ty:ty, $abs:ident, $fma:ident, $pow:ident, $fmod:ident, $round:ident, $sqrt:ident) => {
+        impl Float for $ty {
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                libm::$fma(self, a, b)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                libm::$pow(self, n)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                let r = libm::$fmod(self, rhs);
+                if r < 0.0 {
+                    r + libm::$abs(rhs)
+                } else {
+                    r
+                }
+            }
+
+            fn round(self) -> Self {
+                libm::$round(self)
+            }
+
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+        }
+    };
+}
+
+#[cfg(not(any(feature = "std", test)))]
+impl_float!(f32, fabsf, fmaf, powf, fmodf, roundf, sqrtf);
+#[cfg(not(any(feature = "std", test)))]
+impl_float!(f64, fabs, fma, pow, fmod, round, sqrt);
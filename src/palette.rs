@@ -4,7 +4,19 @@
 //! colors for a theme. Each theme provides the same set of colors with consistent
 //! meanings, making it easy to build UIs that look good across all themes.
 
-use ratatui::style::Color;
+use crate::color::{self, ColorExt, ContrastGrade, HexError};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::names;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use ratatui::style::{Color, Modifier, Style};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// A semantic color palette for a theme.
 ///
@@ -38,7 +50,34 @@ use ratatui::style::Color;
 /// let error_style = Style::default().fg(palette.error);
 /// let success_style = Style::default().fg(palette.success);
 /// let muted_style = Style::default().fg(palette.muted);
+///
+/// // ...or use the ready-made equivalents
+/// assert_eq!(palette.error_style(), error_style);
+/// ```
+///
+/// # Serialization
+///
+/// With the `serde` feature enabled (default), a palette serializes to a map
+/// of field name to `"#rrggbb"` string, e.g. as JSON:
+///
+/// ```json
+/// {
+///   "accent": "#bd93f9",
+///   "secondary": "#ff79c6",
+///   "bg": "#282a36",
+///   "fg": "#f8f8f2",
+///   "muted": "#6272a4",
+///   "selection": "#44475a",
+///   "error": "#ff5555",
+///   "warning": "#ffb86c",
+///   "success": "#50fa7b",
+///   "info": "#8be9fd"
+/// }
 /// ```
+///
+/// RGB colors round-trip exactly. Named and indexed colors are written as
+/// their resolved RGB value, and [`Color::Reset`] as `"reset"`. All ten
+/// fields are required when deserializing; unknown fields are ignored.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ThemePalette {
     /// Primary accent color for highlights and active elements.
@@ -129,11 +168,141 @@ pub struct ThemePalette {
     pub info: Color,
 }
 
+/// Generates a method returning a style with one palette color as foreground.
+macro_rules! fg_styles {
+    ($($(#[$attr:meta])* $name:ident => $field:ident),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            #[must_use]
+            pub const fn $name(&self) -> Style {
+                Style::new().fg(self.$field)
+            }
+        )*
+    };
+}
+
 impl ThemePalette {
+    /// Create a palette from all ten colors, in field declaration order.
+    ///
+    /// The parameters follow the order of the struct fields: `accent`,
+    /// `secondary`, `bg`, `fg`, `muted`, `selection`, `error`, `warning`,
+    /// `success`, `info`. Being `const`, this is a compact alternative to the
+    /// struct literal for palettes embedded in statics, tables and macros.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName, ThemePalette};
+    ///
+    /// const DRACULA: ThemePalette = ThemePalette::new(
+    ///     Color::Rgb(189, 147, 249), // accent
+    ///     Color::Rgb(255, 121, 198), // secondary
+    ///     Color::Rgb(40, 42, 54),    // bg
+    ///     Color::Rgb(248, 248, 242), // fg
+    ///     Color::Rgb(98, 114, 164),  // muted
+    ///     Color::Rgb(68, 71, 90),    // selection
+    ///     Color::Rgb(255, 85, 85),   // error
+    ///     Color::Rgb(255, 184, 108), // warning
+    ///     Color::Rgb(80, 250, 123),  // success
+    ///     Color::Rgb(139, 233, 253), // info
+    /// );
+    /// assert_eq!(DRACULA, ThemeName::Dracula.palette());
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub const fn new(
+        accent: Color,
+        secondary: Color,
+        bg: Color,
+        fg: Color,
+        muted: Color,
+        selection: Color,
+        error: Color,
+        warning: Color,
+        success: Color,
+        info: Color,
+    ) -> Self {
+        Self {
+            accent,
+            secondary,
+            bg,
+            fg,
+            muted,
+            selection,
+            error,
+            warning,
+            success,
+            info,
+        }
+    }
+
+    /// Start building a palette from scratch.
+    ///
+    /// Every color must be set before [`build()`](crate::ThemePaletteBuilder::build)
+    /// succeeds; see [`ThemePaletteBuilder`](crate::ThemePaletteBuilder).
+    #[must_use]
+    pub const fn builder() -> crate::ThemePaletteBuilder {
+        crate::ThemePaletteBuilder::new()
+    }
+
+    /// Returns the perceived brightness of the background, from 0 to 255.
+    ///
+    /// Uses the ITU-R BT.601 formula `(299 R + 587 G + 114 B) / 1000` on the
+    /// gamma-encoded channels, the quick measure behind
+    /// [`is_light()`](Self::is_light). Named ANSI and indexed backgrounds are
+    /// resolved to RGB; a [`Color::Reset`] background has no known brightness
+    /// and yields 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// // Darkest background first, e.g. for a gradient menu.
+    /// let mut themes = ThemeName::all().to_vec();
+    /// themes.sort_by_key(|theme| theme.palette().brightness());
+    /// assert_eq!(themes[0], ThemeName::Cyberpunk);
+    /// assert!(themes.last().unwrap().palette().is_light());
+    /// ```
+    #[must_use]
+    pub const fn brightness(&self) -> u32 {
+        match color::brightness(self.bg) {
+            Some(brightness) => brightness,
+            None => 0,
+        }
+    }
+
+    /// Returns the WCAG relative luminance of the background, from `0.0` to `1.0`.
+    ///
+    /// This is the perceptual measure used by the contrast helpers and
+    /// [`is_light_wcag()`](Self::is_light_wcag); see
+    /// [`relative_luminance()`](Self::relative_luminance) for the formula.
+    /// Returns `None` for a [`Color::Reset`] background, whose on-screen
+    /// value is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let latte = ThemeName::CatppuccinLatte.palette().luminance().unwrap();
+    /// let mocha = ThemeName::CatppuccinMocha.palette().luminance().unwrap();
+    /// assert!(latte > mocha);
+    ///
+    /// assert_eq!(ThemeName::Nord.palette().transparent_bg().luminance(), None);
+    /// ```
+    #[must_use]
+    pub fn luminance(&self) -> Option<f64> {
+        color::luminance(self.bg)
+    }
+
     /// Check if this is a light theme based on background brightness.
     ///
-    /// Uses the perceived brightness formula (ITU-R BT.601) to determine
-    /// if the background color is light enough to be considered a "light theme".
+    /// A theme is light when its [`brightness()`](Self::brightness) exceeds
+    /// 127. This is a fast approximation; see [`is_light_wcag()`](Self::is_light_wcag)
+    /// for a classification consistent with the WCAG contrast helpers.
+    /// A [`Color::Reset`] background has no known brightness and counts as
+    /// dark; see [`is_light_with_terminal()`](Self::is_light_with_terminal).
     ///
     /// # Example
     ///
@@ -144,14 +313,46 @@ impl ThemePalette {
     /// assert!(!ThemeName::Dracula.palette().is_light());
     /// ```
     #[must_use]
-    pub fn is_light(&self) -> bool {
-        if let Color::Rgb(r, g, b) = self.bg {
-            // ITU-R BT.601 perceived brightness formula
-            let brightness = (u32::from(r) * 299 + u32::from(g) * 587 + u32::from(b) * 114) / 1000;
-            brightness > 127
-        } else {
-            false
-        }
+    pub const fn is_light(&self) -> bool {
+        self.brightness() > 127
+    }
+
+    /// Check if this is a light theme using WCAG relative luminance.
+    ///
+    /// A background counts as light when dark text would contrast with it
+    /// better than light text, i.e. when its
+    /// [relative luminance](Self::relative_luminance) exceeds `0.179`, the
+    /// point where the WCAG contrast ratios against black and white are equal.
+    /// This agrees with the crate's contrast helpers by construction. Named
+    /// and indexed backgrounds are resolved to RGB; [`Color::Reset`] counts as
+    /// dark.
+    ///
+    /// [`is_light()`](Self::is_light) remains the cheaper integer check and
+    /// gives the same answer for every built-in theme; prefer this method when
+    /// the classification must be consistent with WCAG contrast results, such
+    /// as for backgrounds near the middle of the brightness range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// assert!(ThemeName::SolarizedLight.palette().is_light_wcag());
+    /// assert!(!ThemeName::Nord.palette().is_light_wcag());
+    ///
+    /// // Near the middle, the two measures can disagree.
+    /// let mut palette = ThemeName::Nord.palette();
+    /// palette.bg = Color::Rgb(0x76, 0x76, 0x76);
+    /// assert!(!palette.is_light());
+    /// assert!(palette.is_light_wcag());
+    /// ```
+    #[must_use]
+    pub fn is_light_wcag(&self) -> bool {
+        /// Luminance at which black and white text have equal contrast:
+        /// `(L + 0.05) / 0.05 == 1.05 / (L + 0.05)`.
+        const CROSSOVER: f64 = 0.179;
+
+        self.luminance().is_some_and(|l| l > CROSSOVER)
     }
 
     /// Check if this is a dark theme.
@@ -167,14 +368,2749 @@ impl ThemePalette {
     /// assert!(!ThemeName::SolarizedLight.palette().is_dark());
     /// ```
     #[must_use]
-    pub fn is_dark(&self) -> bool {
+    pub const fn is_dark(&self) -> bool {
         !self.is_light()
     }
-}
 
-impl Default for ThemePalette {
-    /// Returns the default palette (Dracula theme).
-    fn default() -> Self {
-        crate::ThemeName::default().palette()
+    /// Check if this palette appears light on a terminal with the given background.
+    ///
+    /// Palettes that leave [`bg`](Self::bg) as [`Color::Reset`] (or any other
+    /// non-RGB color) inherit the terminal's background, so [`is_light()`](Self::is_light)
+    /// cannot know how they look. This variant classifies such palettes by
+    /// `terminal_bg` instead, using the same brightness threshold. Palettes
+    /// with an RGB background ignore `terminal_bg`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let mut palette = ThemeName::Nord.palette();
+    /// palette.bg = Color::Reset;
+    ///
+    /// assert!(palette.is_light_with_terminal(Color::Rgb(250, 250, 250)));
+    /// assert!(!palette.is_light_with_terminal(Color::Black));
+    ///
+    /// // An explicit background always wins.
+    /// assert!(!ThemeName::Nord.palette().is_light_with_terminal(Color::White));
+    /// ```
+    #[must_use]
+    pub fn is_light_with_terminal(&self, terminal_bg: Color) -> bool {
+        if matches!(self.bg, Color::Rgb(..)) {
+            self.is_light()
+        } else {
+            color::brightness(terminal_bg).is_some_and(|brightness| brightness > 127)
+        }
+    }
+
+    /// Returns a pair of background colors for a horizontal header gradient.
+    ///
+    /// The gradient starts from [`bg`](Self::bg) blended 30% toward
+    /// [`accent`](Self::accent) and fades back to plain `bg`, so a header
+    /// glows with the theme's accent on one side while its other end merges
+    /// with the rest of the UI. Text in [`fg`](Self::fg) stays readable across
+    /// the whole range. The `GradientLine` widget (`widgets` feature) draws
+    /// it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let palette = ThemeName::Dracula.palette();
+    /// let (start, end) = palette.header_gradient();
+    /// assert_eq!(end, palette.bg);
+    /// assert_ne!(start, palette.bg);
+    /// ```
+    #[must_use]
+    pub fn header_gradient(&self) -> (Color, Color) {
+        (color::blend(self.bg, self.accent, 0.3), self.bg)
+    }
+
+    /// Returns `steps` colors evenly spaced from `from` to `to`, inclusive.
+    ///
+    /// Each RGB channel is interpolated linearly, e.g. to fill a progress bar
+    /// that shifts from [`warning`](Self::warning) to
+    /// [`success`](Self::success) as it fills. Only [`Color::Rgb`] endpoints
+    /// are interpolated; if either is a named, indexed or reset color, each
+    /// step repeats whichever endpoint it is closer to instead. One step
+    /// yields just `from`, and zero steps an empty `Vec`. The result depends
+    /// only on the arguments, not on the palette's own colors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName, ThemePalette};
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    /// let ramp = palette.gradient(palette.warning, palette.success, 5);
+    /// assert_eq!(ramp.len(), 5);
+    /// assert_eq!(ramp[0], palette.warning);
+    /// assert_eq!(ramp[4], palette.success);
+    ///
+    /// let named = palette.gradient(Color::Red, Color::Green, 4);
+    /// assert_eq!(named, [Color::Red, Color::Red, Color::Green, Color::Green]);
+    /// ```
+    #[must_use]
+    // `steps` is a cell count, far below the range where `f32` loses precision.
+    // A method rather than an associated fn so it reads naturally on a palette.
+    #[allow(clippy::cast_precision_loss, clippy::unused_self)]
+    pub fn gradient(&self, from: Color, to: Color, steps: usize) -> Vec<Color> {
+        let last = steps.saturating_sub(1).max(1) as f32;
+        let interpolate = matches!((from, to), (Color::Rgb(..), Color::Rgb(..)));
+        (0..steps)
+            .map(|step| {
+                let t = step as f32 / last;
+                if interpolate {
+                    color::blend(from, to, t)
+                } else if t < 0.5 {
+                    from
+                } else {
+                    to
+                }
+            })
+            .collect()
+    }
+
+    /// Returns background colors for alternating table rows: `(even, odd)`.
+    ///
+    /// Even rows use plain [`bg`](Self::bg); odd rows use `bg` moved 8% toward
+    /// black on light palettes and toward white on dark ones (judged by
+    /// [`is_light()`](Self::is_light)). Flipping the direction keeps the
+    /// stripe visible on every theme, including those whose background is
+    /// already near pure black or white, while staying subtle enough that
+    /// [`fg`](Self::fg) text reads the same on both rows. A
+    /// [`Color::Reset`] background is returned unchanged for both rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Style;
+    /// use ratatui::widgets::Row;
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    /// let (even, odd) = palette.zebra_backgrounds();
+    /// assert_eq!(even, palette.bg);
+    ///
+    /// let rows: Vec<Row> = ["alpha", "beta", "gamma"]
+    ///     .iter()
+    ///     .enumerate()
+    ///     .map(|(i, name)| {
+    ///         let bg = if i % 2 == 0 { even } else { odd };
+    ///         Row::new([*name]).style(Style::new().bg(bg))
+    ///     })
+    ///     .collect();
+    /// ```
+    #[must_use]
+    pub fn zebra_backgrounds(&self) -> (Color, Color) {
+        let toward = if self.is_light() {
+            Color::Rgb(0, 0, 0)
+        } else {
+            Color::Rgb(255, 255, 255)
+        };
+        (self.bg, color::blend(self.bg, toward, 0.08))
+    }
+
+    /// Returns a reduced palette for e-ink and other low-refresh displays.
+    ///
+    /// E-ink panels ghost on subtle shades, so the result uses only pure black,
+    /// pure white and two grays (`#555555` and `#aaaaaa`). The background
+    /// becomes white for light palettes and black for dark ones (judged by
+    /// [`is_light_wcag()`](Self::is_light_wcag)), and the other colors are
+    /// assigned by role:
+    ///
+    /// | Fields | Level |
+    /// |--------|-------|
+    /// | `bg` | paper: white (light) or black (dark) |
+    /// | `fg`, `accent`, `error` | ink: the opposite extreme, for maximum contrast |
+    /// | `secondary`, `muted`, `warning`, `success`, `info` | the gray farther from paper |
+    /// | `selection` | the gray nearer to paper |
+    ///
+    /// Semantic colors can no longer be told apart by hue, so pair them with
+    /// text or symbols (see [`is_monochrome()`](Self::is_monochrome)). The
+    /// mapping depends only on the background, so it is deterministic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let eink = ThemeName::SolarizedLight.palette().for_eink();
+    /// assert_eq!(eink.bg, Color::Rgb(255, 255, 255));
+    /// assert_eq!(eink.fg, Color::Rgb(0, 0, 0));
+    /// assert_eq!(eink.selection, Color::Rgb(170, 170, 170));
+    /// assert!(eink.is_monochrome(0));
+    /// ```
+    #[must_use]
+    pub fn for_eink(&self) -> Self {
+        const fn gray(level: u8) -> Color {
+            Color::Rgb(level, level, level)
+        }
+
+        let (paper, near, far, ink) = if self.is_light_wcag() {
+            (255, 170, 85, 0)
+        } else {
+            (0, 85, 170, 255)
+        };
+        Self {
+            accent: gray(ink),
+            secondary: gray(far),
+            bg: gray(paper),
+            fg: gray(ink),
+            muted: gray(far),
+            selection: gray(near),
+            error: gray(ink),
+            warning: gray(far),
+            success: gray(far),
+            info: gray(far),
+        }
+    }
+
+    /// Returns all ten colors labeled with their field names.
+    ///
+    /// The order matches the struct declaration: `accent`, `secondary`, `bg`,
+    /// `fg`, `muted`, `selection`, `error`, `warning`, `success`, `info`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    /// for (name, color) in palette.colors() {
+    ///     println!("{name}: {color:?}");
+    /// }
+    /// assert_eq!(palette.colors()[0], ("accent", palette.accent));
+    /// ```
+    #[must_use]
+    pub const fn colors(&self) -> [(&'static str, Color); 10] {
+        [
+            ("accent", self.accent),
+            ("secondary", self.secondary),
+            ("bg", self.bg),
+            ("fg", self.fg),
+            ("muted", self.muted),
+            ("selection", self.selection),
+            ("error", self.error),
+            ("warning", self.warning),
+            ("success", self.success),
+            ("info", self.info),
+        ]
+    }
+
+    /// Returns an iterator over the ten labeled colors.
+    ///
+    /// Yields the same `(field name, color)` pairs as
+    /// [`colors()`](Self::colors), in the same order, without allocating. The
+    /// iterator owns a copy of the colors, so it does not borrow the palette.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let palette = ThemeName::Kanagawa.palette();
+    /// for (name, color) in palette.iter_fields() {
+    ///     println!("{name}: {color:?}");
+    /// }
+    ///
+    /// let mut fields = palette.iter_fields();
+    /// assert_eq!(fields.len(), 10);
+    /// assert_eq!(fields.next(), Some(("accent", palette.accent)));
+    /// assert_eq!(fields.last(), Some(("info", palette.info)));
+    /// ```
+    #[must_use]
+    pub fn iter_fields(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (&'static str, Color)> + ExactSizeIterator {
+        self.colors().into_iter()
+    }
+
+    /// Returns an iterator over the ten labeled colors.
+    ///
+    /// Shorthand for [`iter_fields()`](Self::iter_fields). The order is
+    /// stable and matches the struct declaration: `accent`, `secondary`,
+    /// `bg`, `fg`, `muted`, `selection`, `error`, `warning`, `success`,
+    /// `info`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::text::{Line, Span};
+    /// use ratatui_themes::{Style, ThemeName};
+    ///
+    /// let palette = ThemeName::Dracula.palette();
+    /// let overlay: Vec<Line<'_>> = palette
+    ///     .iter()
+    ///     .map(|(name, color)| Line::from(vec![
+    ///         Span::styled("██ ", Style::new().fg(color)),
+    ///         Span::raw(name),
+    ///     ]))
+    ///     .collect();
+    ///
+    /// assert_eq!(overlay.len(), 10);
+    /// ```
+    #[must_use]
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (&'static str, Color)> + ExactSizeIterator {
+        self.iter_fields()
+    }
+
+    /// Returns the labeled colors sorted from lightest to darkest.
+    ///
+    /// Brightness is WCAG relative luminance. Colors without a fixed value
+    /// ([`Color::Reset`]) sort last, and ties keep the [`colors()`](Self::colors)
+    /// order, so the result is deterministic for a given palette.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let sorted = ThemeName::Dracula.palette().fields_by_luminance();
+    /// assert_eq!(sorted.first().map(|(name, _)| *name), Some("fg"));
+    /// assert_eq!(sorted.last().map(|(name, _)| *name), Some("bg"));
+    /// ```
+    #[must_use]
+    pub fn fields_by_luminance(&self) -> Vec<(&'static str, Color)> {
+        let mut fields = self.colors().to_vec();
+        fields.sort_by(
+            |(_, a), (_, b)| match (color::luminance(*a), color::luminance(*b)) {
+                (Some(la), Some(lb)) => lb.total_cmp(&la),
+                (Some(_), None) => core::cmp::Ordering::Less,
+                (None, Some(_)) => core::cmp::Ordering::Greater,
+                (None, None) => core::cmp::Ordering::Equal,
+            },
+        );
+        fields
+    }
+
+    /// Lists the fields whose colors differ from `other`.
+    ///
+    /// Each entry is `(field name, self color, other color)`, in
+    /// [`colors()`](Self::colors) order. Colors are compared as values, so
+    /// [`Color::White`] and `Color::Rgb(255, 255, 255)` count as different.
+    /// Identical palettes yield an empty list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let base = ThemeName::Nord.palette();
+    /// let custom = base.with_accent(Color::Rgb(235, 203, 139));
+    ///
+    /// assert_eq!(
+    ///     custom.diff(&base),
+    ///     vec![("accent", Color::Rgb(235, 203, 139), base.accent)]
+    /// );
+    /// assert!(base.diff(&base).is_empty());
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<(&'static str, Color, Color)> {
+        self.iter_fields()
+            .zip(other.iter_fields())
+            .filter(|((_, a), (_, b))| a != b)
+            .map(|((name, a), (_, b))| (name, a, b))
+            .collect()
+    }
+
+    /// Resolve any color to its RGB channels.
+    ///
+    /// [`Color::Rgb`] is returned as is. Named ANSI colors map to the xterm
+    /// defaults (e.g. [`Color::Red`] is `(205, 0, 0)`), and indexed colors
+    /// follow the standard 256-color layout: the 16 ANSI colors, a 6×6×6
+    /// cube, then a 24-step grayscale ramp. [`Color::Reset`] has no fixed
+    /// value and resolves to black.
+    ///
+    /// The palette transforms ([`tint()`](Self::tint),
+    /// [`soften_extremes()`](Self::soften_extremes), ...) use the same
+    /// resolution, so they work on palettes built from named or indexed
+    /// colors too.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemePalette};
+    ///
+    /// assert_eq!(ThemePalette::resolve_rgb(Color::Rgb(1, 2, 3)), (1, 2, 3));
+    /// assert_eq!(ThemePalette::resolve_rgb(Color::White), (255, 255, 255));
+    /// assert_eq!(ThemePalette::resolve_rgb(Color::Indexed(196)), (255, 0, 0));
+    /// assert_eq!(ThemePalette::resolve_rgb(Color::Indexed(232)), (8, 8, 8));
+    /// ```
+    #[must_use]
+    pub const fn resolve_rgb(color: Color) -> (u8, u8, u8) {
+        match color::to_rgb(color) {
+            Some(rgb) => rgb,
+            None => (0, 0, 0),
+        }
+    }
+
+    /// Returns the name of the CSS/X11 named color nearest to `color`.
+    ///
+    /// Names are approximate: the result is whichever of the 139 CSS named
+    /// colors is closest in RGB space, not an exact match, so Dracula's
+    /// `#ff5555` error red is described as `"tomato"`. Multi-word names
+    /// are separated by spaces. Named and indexed colors are resolved to RGB
+    /// first (see [`resolve_rgb()`](Self::resolve_rgb)); [`Color::Reset`]
+    /// has no fixed value and yields `"terminal default"`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName, ThemePalette};
+    ///
+    /// assert_eq!(ThemePalette::nearest_color_name(Color::Rgb(255, 99, 71)), "tomato");
+    /// assert_eq!(ThemePalette::nearest_color_name(ThemeName::Dracula.palette().error), "tomato");
+    ///
+    /// for (field, color) in ThemeName::Nord.palette().colors() {
+    ///     println!("{field}: {}", ThemePalette::nearest_color_name(color));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn nearest_color_name(color: Color) -> &'static str {
+        names::nearest(color)
+    }
+
+    /// Format a [`Color::Rgb`] as a lowercase `#rrggbb` string, e.g. for CSS.
+    ///
+    /// Every other color yields `None`: named and indexed colors depend on the
+    /// terminal's palette, and [`Color::Reset`] has no value at all. Pass such
+    /// colors through [`resolve_rgb()`](Self::resolve_rgb) first to write
+    /// their standard values instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemePalette};
+    ///
+    /// assert_eq!(ThemePalette::to_hex(Color::Rgb(189, 147, 249)).as_deref(), Some("#bd93f9"));
+    /// assert_eq!(ThemePalette::to_hex(Color::White), None);
+    /// assert_eq!(ThemePalette::to_hex(Color::Reset), None);
+    ///
+    /// let (r, g, b) = ThemePalette::resolve_rgb(Color::White);
+    /// assert_eq!(ThemePalette::to_hex(Color::Rgb(r, g, b)).as_deref(), Some("#ffffff"));
+    /// ```
+    #[must_use]
+    pub fn to_hex(color: Color) -> Option<String> {
+        match color {
+            Color::Rgb(r, g, b) => Some(color::hex_string((r, g, b))),
+            _ => None,
+        }
+    }
+
+    /// Parse a `#rgb`, `#rrggbb` or `#rrggbbaa` hex string into a [`Color::Rgb`].
+    ///
+    /// The leading `#` is optional and digits are case-insensitive. Terminal
+    /// cells have no transparency, so the alpha channel of the eight-digit
+    /// form is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HexError::InvalidDigit`](crate::HexError::InvalidDigit) for
+    /// a character that is not a hex digit and
+    /// [`HexError::InvalidLength`](crate::HexError::InvalidLength) for any
+    /// other number of digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, HexError, ThemePalette};
+    ///
+    /// assert_eq!(ThemePalette::from_hex("#bd93f9"), Ok(Color::Rgb(189, 147, 249)));
+    /// assert_eq!(ThemePalette::from_hex("#fff"), Ok(Color::Rgb(255, 255, 255)));
+    /// assert_eq!(ThemePalette::from_hex("#bd93f9cc"), Ok(Color::Rgb(189, 147, 249)));
+    /// assert_eq!(ThemePalette::from_hex("#bd93"), Err(HexError::InvalidLength(4)));
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Color, HexError> {
+        match color::parse_hex_color(hex) {
+            Ok((r, g, b)) => Ok(Color::Rgb(r, g, b)),
+            Err(err) => {
+                log_fallback!("invalid hex color {hex:?}: {err}");
+                Err(err)
+            }
+        }
+    }
+
+    /// Returns every color as a `#rrggbb` string, keyed by field name.
+    ///
+    /// Colors are formatted with [`to_hex()`](Self::to_hex), so only
+    /// [`Color::Rgb`] fields are included; named, indexed and
+    /// [`Color::Reset`] fields are left out. The built-in palettes are all
+    /// RGB, so their maps have all ten fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let hex = ThemeName::Dracula.palette().to_hex_map();
+    /// assert_eq!(hex["accent"], "#bd93f9");
+    ///
+    /// let css: String = hex
+    ///     .iter()
+    ///     .map(|(field, value)| format!("  --theme-{field}: {value};\n"))
+    ///     .collect();
+    /// assert!(css.contains("--theme-bg: #282a36;"));
+    /// ```
+    #[must_use]
+    pub fn to_hex_map(&self) -> BTreeMap<&'static str, String> {
+        self.iter_fields()
+            .filter_map(|(field, color)| Self::to_hex(color).map(|hex| (field, hex)))
+            .collect()
+    }
+
+    /// WCAG 2.1 relative luminance of a color, from `0.0` (black) to `1.0` (white).
+    ///
+    /// Each sRGB channel is normalized to `0.0..=1.0` and linearized
+    /// (`c / 12.92` up to `0.03928`, otherwise `((c + 0.055) / 1.055)^2.4`),
+    /// then weighted as `0.2126 R + 0.7152 G + 0.0722 B`. Named ANSI colors use
+    /// the xterm defaults and indexed colors the standard 256-color layout.
+    /// [`Color::Reset`] has no known value and yields `0.0`.
+    ///
+    /// This is the measure behind the crate's contrast helpers. It differs
+    /// from the quick BT.601 brightness used by [`is_light()`](Self::is_light),
+    /// which works on gamma-encoded channels and is cheaper but less accurate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemePalette};
+    ///
+    /// assert_eq!(ThemePalette::relative_luminance(Color::Rgb(0, 0, 0)), 0.0);
+    /// assert_eq!(ThemePalette::relative_luminance(Color::White), 1.0);
+    /// assert!(ThemePalette::relative_luminance(Color::Indexed(244)) > 0.2);
+    /// ```
+    #[must_use]
+    pub fn relative_luminance(color: Color) -> f64 {
+        color::luminance(color).unwrap_or(0.0)
+    }
+
+    /// WCAG 2.1 contrast ratio between two colors, from `1.0` to `21.0`.
+    ///
+    /// Computed as `(L1 + 0.05) / (L2 + 0.05)`, where `L1` is the
+    /// [relative luminance](Self::relative_luminance) of the lighter color,
+    /// so the order of the arguments does not matter. Named ANSI and indexed
+    /// colors are resolved to their standard sRGB values first. A
+    /// [`Color::Reset`] on either side has no known value and yields `1.0`.
+    /// WCAG AA asks for at least 4.5:1 for body text.
+    ///
+    /// Returns an `f64` rather than `f32`, matching
+    /// [`relative_luminance()`](Self::relative_luminance), so ratios right at
+    /// a WCAG threshold compare reliably.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let palette = ThemeName::Dracula.palette();
+    /// let ratio = palette.contrast_ratio(Color::White, Color::Black);
+    /// assert!((ratio - 21.0).abs() < 1e-9);
+    /// assert!(palette.contrast_ratio(palette.accent, palette.bg) >= 4.5);
+    /// assert_eq!(palette.contrast_ratio(Color::Reset, Color::Black), 1.0);
+    /// ```
+    #[must_use]
+    // A method rather than an associated fn so it reads naturally on a palette.
+    #[allow(clippy::unused_self)]
+    pub fn contrast_ratio(&self, fg: Color, bg: Color) -> f64 {
+        color::contrast_ratio(fg, bg)
+    }
+
+    /// WCAG 2.1 contrast ratio of [`fg`](Self::fg) against [`bg`](Self::bg).
+    ///
+    /// Shorthand for `palette.contrast_ratio(palette.fg, palette.bg)`;
+    /// see [`contrast_grade()`](Self::contrast_grade) for the graded form.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert!(ThemeName::Dracula.palette().contrast_ratio_fg_bg() > 4.5);
+    /// ```
+    #[must_use]
+    pub fn contrast_ratio_fg_bg(&self) -> f64 {
+        color::contrast_ratio(self.fg, self.bg)
+    }
+
+    /// Grade the contrast of [`fg`](Self::fg) against [`bg`](Self::bg).
+    ///
+    /// Uses the WCAG 2.1 contrast ratio and the thresholds described on
+    /// [`ContrastGrade`]. If either color is [`Color::Reset`] the contrast is
+    /// unknown and the grade is [`ContrastGrade::Fail`], so check for
+    /// `Reset` first when grading palettes from
+    /// [`transparent_bg()`](Self::transparent_bg).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{ContrastGrade, ThemeName};
+    ///
+    /// assert_eq!(ThemeName::Dracula.palette().contrast_grade(), ContrastGrade::Aaa);
+    /// ```
+    #[must_use]
+    pub fn contrast_grade(&self) -> ContrastGrade {
+        ContrastGrade::from_ratio(color::contrast_ratio(self.fg, self.bg))
+    }
+
+    /// Returns a text color that stays readable on an arbitrary background.
+    ///
+    /// Returns [`fg`](Self::fg) when it reaches 4.5:1 contrast against `bg`
+    /// (WCAG AA for body text). Otherwise the highest-contrast of `fg`, the
+    /// palette's own [`bg`](Self::bg), black and white is returned, as
+    /// picked by [`best_text_color`](crate::best_text_color). Useful for
+    /// badges and tags drawn on colors outside the palette.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Style;
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let palette = ThemeName::Dracula.palette();
+    /// assert_eq!(palette.readable_on(Color::Rgb(0, 0, 0)), palette.fg);
+    /// assert_ne!(palette.readable_on(Color::Rgb(255, 255, 255)), palette.fg);
+    ///
+    /// let badge_bg = Color::Rgb(250, 220, 80);
+    /// let badge = Style::new().bg(badge_bg).fg(palette.readable_on(badge_bg));
+    /// ```
+    #[must_use]
+    pub fn readable_on(&self, bg: Color) -> Color {
+        if color::contrast_ratio(self.fg, bg) >= 4.5 {
+            return self.fg;
+        }
+        color::best_text_color(
+            bg,
+            &[
+                self.fg,
+                self.bg,
+                Color::Rgb(0, 0, 0),
+                Color::Rgb(255, 255, 255),
+            ],
+        )
+    }
+
+    /// Compute the WCAG contrast ratio between every pair of palette colors.
+    ///
+    /// Rows and columns follow the order of [`colors()`](Self::colors), so
+    /// `matrix[i][j]` is the ratio between `colors()[i]` and `colors()[j]`.
+    /// The matrix is symmetric with `1.0` on the diagonal. Pairs involving a
+    /// color that cannot be resolved to RGB (such as [`Color::Reset`]) are
+    /// also `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    /// let matrix = palette.contrast_matrix();
+    ///
+    /// // `fg` is the 4th field and `bg` the 3rd.
+    /// assert_eq!(matrix[3][2], matrix[2][3]);
+    /// assert!(matrix[3][2] > 4.5);
+    /// ```
+    #[must_use]
+    pub fn contrast_matrix(&self) -> [[f64; 10]; 10] {
+        let colors = self.colors();
+        let mut matrix = [[1.0; 10]; 10];
+        for (i, &(_, a)) in colors.iter().enumerate() {
+            for (j, &(_, b)) in colors.iter().enumerate().skip(i + 1) {
+                let ratio = color::contrast_ratio(a, b);
+                matrix[i][j] = ratio;
+                matrix[j][i] = ratio;
+            }
+        }
+        matrix
+    }
+
+    /// Returns `n` visually distinct colors for labeling categories.
+    ///
+    /// The palette's own hued colors come first, in the order `accent`,
+    /// `secondary`, `info`, `success`, `warning`, `error`, skipping grays and
+    /// duplicates. When `n` exceeds that, extra colors are generated by
+    /// rotating around the color wheel by the golden angle from the accent's
+    /// hue, at the average saturation and lightness of the palette's hues.
+    /// Once a full turn of hues has been used, the saturation and lightness
+    /// are shifted to a new band and the rotation continues. Every palette
+    /// yields more than a thousand unique colors; past that the sequence
+    /// repeats from the start, so the result always holds exactly `n`
+    /// colors. The
+    /// result is deterministic, so category colors stay stable between runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let palette = ThemeName::Dracula.palette();
+    /// let colors = palette.distinct_colors(12);
+    ///
+    /// assert_eq!(colors.len(), 12);
+    /// assert_eq!(colors[0], palette.accent);
+    /// ```
+    #[must_use]
+    pub fn distinct_colors(&self, n: usize) -> Vec<Color> {
+        /// Golden angle in degrees; successive hues never line up.
+        const GOLDEN_ANGLE: f32 = 137.508;
+        /// Saturation and lightness offsets tried in turn, 360 hues each.
+        const BANDS: [(f32, f32); 6] = [
+            (0.0, 0.0),
+            (0.0, 0.15),
+            (0.0, -0.15),
+            (-0.3, 0.0),
+            (-0.3, 0.15),
+            (-0.3, -0.15),
+        ];
+
+        let mut hues: Vec<(Color, (f32, f32, f32))> = Vec::new();
+        for candidate in [
+            self.accent,
+            self.secondary,
+            self.info,
+            self.success,
+            self.warning,
+            self.error,
+        ] {
+            let Some((r, g, b)) = color::to_rgb(candidate) else {
+                continue;
+            };
+            let hsl = color::rgb_to_hsl(r, g, b);
+            if hsl.1 >= 0.15 && hues.iter().all(|&(c, _)| c != candidate) {
+                hues.push((candidate, hsl));
+            }
+        }
+
+        let mut colors: Vec<Color> = hues.iter().take(n).map(|&(c, _)| c).collect();
+        if colors.len() < n {
+            #[allow(clippy::cast_precision_loss)] // at most six hues
+            let (start, saturation, lightness) = match hues.first() {
+                Some(&(_, (h, _, _))) => {
+                    let count = hues.len() as f32;
+                    let s = hues.iter().map(|(_, (_, s, _))| s).sum::<f32>() / count;
+                    let l = hues.iter().map(|(_, (_, _, l))| l).sum::<f32>() / count;
+                    (h, s, l)
+                }
+                None => (0.0, 0.6, 0.6),
+            };
+
+            let mut seen: BTreeSet<(u8, u8, u8)> =
+                colors.iter().filter_map(|&c| color::to_rgb(c)).collect();
+            let mut step = 1.0;
+            'bands: for (ds, dl) in BANDS {
+                let s = (saturation + ds).clamp(0.1, 1.0);
+                let l = (lightness + dl).clamp(0.15, 0.85);
+                for _ in 0..360 {
+                    if colors.len() >= n {
+                        break 'bands;
+                    }
+                    let generated = color::hsl_to_rgb(GOLDEN_ANGLE.mul_add(step, start), s, l);
+                    if color::to_rgb(generated).is_some_and(|rgb| seen.insert(rgb)) {
+                        colors.push(generated);
+                    }
+                    step += 1.0;
+                }
+            }
+
+            // Every band is used up: repeat the sequence rather than loop forever.
+            let unique = colors.len();
+            for i in unique..n {
+                let Some(&repeat) = colors.get(i % unique.max(1)) else {
+                    break;
+                };
+                colors.push(repeat);
+            }
+        }
+        colors
+    }
+
+    /// Check whether every color in the palette is a shade of gray.
+    ///
+    /// A color counts as gray when its three channels are within `tolerance`
+    /// of each other. Named and indexed colors are resolved to their standard
+    /// RGB values first; [`Color::Reset`] carries no hue and is ignored.
+    ///
+    /// Use this to switch to shape- or pattern-based cues when a theme cannot
+    /// convey meaning through color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName, ThemePalette};
+    ///
+    /// assert!(!ThemeName::Dracula.palette().is_monochrome(8));
+    ///
+    /// let gray = Color::Rgb(128, 128, 130);
+    /// let palette = ThemePalette {
+    ///     accent: gray, secondary: gray, bg: Color::Black, fg: Color::White,
+    ///     muted: gray, selection: gray, error: gray, warning: gray,
+    ///     success: gray, info: Color::Reset,
+    /// };
+    /// assert!(palette.is_monochrome(2));
+    /// assert!(!palette.is_monochrome(1));
+    /// ```
+    #[must_use]
+    pub fn is_monochrome(&self, tolerance: u8) -> bool {
+        self.colors()
+            .iter()
+            .filter_map(|&(_, c)| color::to_rgb(c))
+            .all(|(r, g, b)| r.max(g).max(b) - r.min(g).min(b) <= tolerance)
+    }
+
+    /// Returns a stable 64-bit fingerprint of the palette's colors.
+    ///
+    /// The hash is FNV-1a over a fixed byte encoding of the ten colors in
+    /// [`colors()`](Self::colors) order, so it is identical across runs,
+    /// platforms, and crate builds — unlike `std`'s `DefaultHasher`. Use it to
+    /// cache or compare palettes cheaply.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let nord = ThemeName::Nord.palette();
+    /// assert_eq!(nord.fingerprint(), ThemeName::Nord.fingerprint());
+    /// assert_ne!(nord.fingerprint(), ThemeName::Dracula.fingerprint());
+    /// ```
+    #[must_use]
+    pub const fn fingerprint(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let colors = self.colors();
+        let mut hash = FNV_OFFSET;
+        let mut i = 0;
+        while i < colors.len() {
+            let bytes = color::stable_bytes(colors[i].1);
+            let mut j = 0;
+            while j < bytes.len() {
+                hash ^= bytes[j] as u64;
+                hash = hash.wrapping_mul(FNV_PRIME);
+                j += 1;
+            }
+            i += 1;
+        }
+        hash
+    }
+
+    /// Returns the built-in theme this palette is an unmodified copy of, if any.
+    ///
+    /// Useful for labeling a user's palette as "Nord" versus "Nord (modified)".
+    /// For the closest built-in to an edited palette, use
+    /// [`nearest_theme()`](Self::nearest_theme).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let mut palette = ThemeName::Nord.palette();
+    /// assert_eq!(palette.matches_builtin(), Some(ThemeName::Nord));
+    ///
+    /// palette.accent = Color::Rgb(255, 0, 0);
+    /// assert_eq!(palette.matches_builtin(), None);
+    /// ```
+    #[must_use]
+    pub fn matches_builtin(&self) -> Option<crate::ThemeName> {
+        let fingerprint = self.fingerprint();
+        crate::ThemeName::all()
+            .iter()
+            .copied()
+            .find(|theme| theme.fingerprint() == fingerprint && theme.palette() == *self)
+    }
+
+    /// Returns the built-in theme most similar to this palette, with its distance.
+    ///
+    /// The distance is the sum over all ten fields of the Euclidean distance
+    /// between the two colors in RGB space, each color resolved with
+    /// [`resolve_rgb()`](Self::resolve_rgb). It is `0.0` for an exact match
+    /// and at most about `4416` (ten times the black-to-white distance). Ties
+    /// go to the theme listed first in [`ThemeName::all()`](crate::ThemeName::all).
+    ///
+    /// Unlike [`matches_builtin()`](Self::matches_builtin), this always
+    /// returns a theme, which suits "Closest to: Nord" labels for custom
+    /// palettes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let mut palette = ThemeName::Nord.palette();
+    /// palette.accent = Color::Rgb(140, 190, 210);
+    ///
+    /// let (nearest, distance) = palette.nearest_theme();
+    /// assert_eq!(nearest, ThemeName::Nord);
+    /// assert!(distance > 0.0 && distance < 10.0);
+    /// ```
+    #[must_use]
+    pub fn nearest_theme(&self) -> (crate::ThemeName, f64) {
+        let distance = |other: &Self| -> f64 {
+            self.colors()
+                .iter()
+                .zip(other.colors())
+                .map(|(&(_, a), (_, b))| {
+                    let (r1, g1, b1) = Self::resolve_rgb(a);
+                    let (r2, g2, b2) = Self::resolve_rgb(b);
+                    let dr = f64::from(r1) - f64::from(r2);
+                    let dg = f64::from(g1) - f64::from(g2);
+                    let db = f64::from(b1) - f64::from(b2);
+                    db.mul_add(db, dr.mul_add(dr, dg * dg)).sqrt()
+                })
+                .sum()
+        };
+
+        crate::ThemeName::all()
+            .iter()
+            .map(|&theme| (theme, distance(&theme.palette())))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or_default()
+    }
+
+    /// Returns the most legible text color for content drawn on [`accent`](Self::accent).
+    ///
+    /// Use this for button and badge labels that sit on an accent background.
+    /// The palette's own [`bg`](Self::bg) or [`fg`](Self::fg) is preferred when
+    /// it reaches WCAG AA (4.5:1); otherwise the highest-contrast choice among
+    /// `bg`, `fg`, pure black, and pure white is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Style;
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let palette = ThemeName::Dracula.palette();
+    /// let button = Style::default().fg(palette.on_accent()).bg(palette.accent);
+    ///
+    /// // Dracula's dark background reads well on its light purple accent.
+    /// assert_eq!(palette.on_accent(), palette.bg);
+    /// ```
+    #[must_use]
+    pub fn on_accent(&self) -> Color {
+        self.text_on(self.accent)
+    }
+
+    /// Returns the most legible text color for content drawn on [`secondary`](Self::secondary).
+    ///
+    /// See [`on_accent()`](Self::on_accent) for how the color is chosen.
+    #[must_use]
+    pub fn on_secondary(&self) -> Color {
+        self.text_on(self.secondary)
+    }
+
+    /// Returns the most legible text color for content drawn on [`selection`](Self::selection).
+    ///
+    /// See [`on_accent()`](Self::on_accent) for how the color is chosen.
+    #[must_use]
+    pub fn on_selection(&self) -> Color {
+        self.text_on(self.selection)
+    }
+
+    /// Returns the most legible text color for content drawn on [`error`](Self::error).
+    ///
+    /// See [`on_accent()`](Self::on_accent) for how the color is chosen.
+    #[must_use]
+    pub fn on_error(&self) -> Color {
+        self.text_on(self.error)
+    }
+
+    /// Returns the most legible text color for content drawn on [`warning`](Self::warning).
+    ///
+    /// See [`on_accent()`](Self::on_accent) for how the color is chosen.
+    #[must_use]
+    pub fn on_warning(&self) -> Color {
+        self.text_on(self.warning)
+    }
+
+    /// Returns the most legible text color for content drawn on [`success`](Self::success).
+    ///
+    /// See [`on_accent()`](Self::on_accent) for how the color is chosen.
+    #[must_use]
+    pub fn on_success(&self) -> Color {
+        self.text_on(self.success)
+    }
+
+    /// Returns the most legible text color for content drawn on [`info`](Self::info).
+    ///
+    /// See [`on_accent()`](Self::on_accent) for how the color is chosen.
+    #[must_use]
+    pub fn on_info(&self) -> Color {
+        self.text_on(self.info)
+    }
+
+    /// Build a palette from a 16-color ANSI terminal scheme.
+    ///
+    /// `colors` is in standard ANSI order (0–7 normal, 8–15 bright), e.g. the
+    /// `color0`…`color15` entries of an `.Xresources` file. Slots map to
+    /// semantic fields as follows:
+    ///
+    /// | Field | ANSI slot |
+    /// |-------|-----------|
+    /// | `bg` | 0 — black |
+    /// | `error` | 1 — red |
+    /// | `success` | 2 — green |
+    /// | `warning` | 3 — yellow |
+    /// | `info` | 4 — blue |
+    /// | `secondary` | 5 — magenta |
+    /// | `fg` | 7 — white |
+    /// | `muted` | 8 — bright black |
+    /// | `accent` | 12 — bright blue |
+    /// | `selection` | halfway between 0 and 8 (slot 8 if either is [`Color::Reset`]) |
+    ///
+    /// Slots 6, 9–11 and 13–15 are not used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemePalette};
+    ///
+    /// let palette = ThemePalette::from_ansi16([
+    ///     Color::Rgb(0, 0, 0), Color::Rgb(204, 0, 0), Color::Rgb(78, 154, 6),
+    ///     Color::Rgb(196, 160, 0), Color::Rgb(52, 101, 164), Color::Rgb(117, 80, 123),
+    ///     Color::Rgb(6, 152, 154), Color::Rgb(211, 215, 207), Color::Rgb(85, 87, 83),
+    ///     Color::Rgb(239, 41, 41), Color::Rgb(138, 226, 52), Color::Rgb(252, 233, 79),
+    ///     Color::Rgb(114, 159, 207), Color::Rgb(173, 127, 168), Color::Rgb(52, 226, 226),
+    ///     Color::Rgb(238, 238, 236),
+    /// ]);
+    ///
+    /// assert_eq!(palette.bg, Color::Rgb(0, 0, 0));
+    /// assert_eq!(palette.error, Color::Rgb(204, 0, 0));
+    /// assert_eq!(palette.selection, Color::Rgb(43, 44, 42));
+    /// ```
+    #[must_use]
+    pub fn from_ansi16(colors: [Color; 16]) -> Self {
+        let (bg, muted) = (colors[0], colors[8]);
+        let selection = match (color::to_rgb(bg), color::to_rgb(muted)) {
+            (Some(_), Some(_)) => color::blend(bg, muted, 0.5),
+            _ => muted,
+        };
+
+        Self {
+            accent: colors[12],
+            secondary: colors[5],
+            bg,
+            fg: colors[7],
+            muted,
+            selection,
+            error: colors[1],
+            warning: colors[3],
+            success: colors[2],
+            info: colors[4],
+        }
+    }
+
+    /// Build a palette from a [base16](https://github.com/chriskempson/base16) scheme.
+    ///
+    /// `colors` holds `base00`…`base0F` in order. The base16 styling roles
+    /// map to semantic fields as follows:
+    ///
+    /// | Field | base16 slot | base16 role |
+    /// |-------|-------------|-------------|
+    /// | `bg` | `base00` | default background |
+    /// | `selection` | `base02` | selection background |
+    /// | `muted` | `base03` | comments |
+    /// | `fg` | `base05` | default foreground |
+    /// | `error` | `base08` | red: variables, deleted |
+    /// | `warning` | `base0A` | yellow: classes, search highlight |
+    /// | `success` | `base0B` | green: strings, inserted |
+    /// | `info` | `base0C` | cyan: support, escape characters |
+    /// | `accent` | `base0D` | blue: functions, headings |
+    /// | `secondary` | `base0E` | magenta: keywords, changed |
+    ///
+    /// The remaining shades (`base01`, `base04`, `base06`, `base07`) and
+    /// accents (`base09`, `base0F`) are not used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemePalette};
+    ///
+    /// // base16 "Ocean"
+    /// let palette = ThemePalette::from_base16_hex(&[
+    ///     "2b303b", "343d46", "4f5b66", "65737e", "a7adba", "c0c5ce", "dfe1e8", "eff1f5",
+    ///     "bf616a", "d08770", "ebcb8b", "a3be8c", "96b5b4", "8fa1b3", "b48ead", "ab7967",
+    /// ])?;
+    /// assert_eq!(palette.bg, Color::Rgb(0x2b, 0x30, 0x3b));
+    /// assert_eq!(palette.accent, Color::Rgb(0x8f, 0xa1, 0xb3));
+    /// # Ok::<(), ratatui_themes::HexError>(())
+    /// ```
+    #[must_use]
+    pub const fn from_base16(colors: [Color; 16]) -> Self {
+        Self {
+            accent: colors[0x0D],
+            secondary: colors[0x0E],
+            bg: colors[0x00],
+            fg: colors[0x05],
+            muted: colors[0x03],
+            selection: colors[0x02],
+            error: colors[0x08],
+            warning: colors[0x0A],
+            success: colors[0x0B],
+            info: colors[0x0C],
+        }
+    }
+
+    /// Build a palette from a base16 scheme given as hex strings.
+    ///
+    /// Each entry is parsed with [`from_hex()`](Self::from_hex), so the
+    /// bare `"2b303b"` form used by base16 scheme files works as well as
+    /// `"#2b303b"`. See [`from_base16()`](Self::from_base16) for how slots
+    /// map to fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`HexError`] of the first entry that is not a valid hex
+    /// color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{HexError, ThemePalette};
+    ///
+    /// let mut scheme = ["000000"; 16];
+    /// scheme[8] = "red";
+    /// assert_eq!(ThemePalette::from_base16_hex(&scheme), Err(HexError::InvalidDigit('r')));
+    /// ```
+    pub fn from_base16_hex(colors: &[&str; 16]) -> Result<Self, HexError> {
+        let mut parsed = [Color::Reset; 16];
+        for (slot, hex) in parsed.iter_mut().zip(colors) {
+            *slot = Self::from_hex(hex)?;
+        }
+        Ok(Self::from_base16(parsed))
+    }
+
+    /// Lightness added to a normal ANSI color to derive its bright variant in
+    /// [`ansi16()`](Self::ansi16).
+    pub const ANSI_BRIGHT_STEP: f32 = 0.1;
+
+    /// Map the palette onto the 16 ANSI color slots.
+    ///
+    /// Returns colors in standard ANSI order (0–7 normal, 8–15 bright), for
+    /// terminals limited to 16 colors or for writing a terminal color scheme
+    /// that matches the theme. Slots are filled as follows:
+    ///
+    /// | ANSI slot | Color |
+    /// |-----------|-------|
+    /// | 0 — black | `bg` |
+    /// | 1 — red | `error` |
+    /// | 2 — green | `success` |
+    /// | 3 — yellow | `warning` |
+    /// | 4 — blue | `info` |
+    /// | 5 — magenta | `secondary` |
+    /// | 6 — cyan | halfway between `info` and `success` |
+    /// | 7 — white | `fg` |
+    /// | 8 — bright black | `muted` |
+    /// | 9–11, 13, 14 — bright red, green, yellow, magenta, cyan | slots 1–3, 5 and 6 lightened by [`ANSI_BRIGHT_STEP`](Self::ANSI_BRIGHT_STEP) |
+    /// | 12 — bright blue | `accent` |
+    /// | 15 — bright white | `fg` lightened by [`ANSI_BRIGHT_STEP`](Self::ANSI_BRIGHT_STEP) |
+    ///
+    /// The slots shared with [`from_ansi16()`](Self::from_ansi16) match it,
+    /// so converting back restores every field except `selection`. Only RGB
+    /// colors are lightened; named colors are repeated in their bright slot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{ThemeName, ThemePalette};
+    ///
+    /// let palette = ThemeName::GruvboxDark.palette();
+    /// let ansi = palette.ansi16();
+    /// assert_eq!(ansi[1], palette.error);
+    /// assert_eq!(ansi[12], palette.accent);
+    ///
+    /// let back = ThemePalette::from_ansi16(ansi);
+    /// assert_eq!(ThemePalette { selection: palette.selection, ..back }, palette);
+    /// ```
+    #[must_use]
+    pub fn ansi16(&self) -> [Color; 16] {
+        let cyan = color::blend(self.info, self.success, 0.5);
+        let bright = |color: Color| color.lighten(Self::ANSI_BRIGHT_STEP);
+        [
+            self.bg,
+            self.error,
+            self.success,
+            self.warning,
+            self.info,
+            self.secondary,
+            cyan,
+            self.fg,
+            self.muted,
+            bright(self.error),
+            bright(self.success),
+            bright(self.warning),
+            self.accent,
+            bright(self.secondary),
+            bright(cyan),
+            bright(self.fg),
+        ]
+    }
+
+    /// Returns the semantic color for a notification level.
+    #[must_use]
+    pub const fn level_color(&self, level: NotificationLevel) -> Color {
+        match level {
+            NotificationLevel::Info => self.info,
+            NotificationLevel::Success => self.success,
+            NotificationLevel::Warning => self.warning,
+            NotificationLevel::Error => self.error,
+        }
+    }
+
+    /// Returns a style in the level's semantic color with `modifiers` added.
+    ///
+    /// This expresses the whole "how we show an error" decision in one call,
+    /// e.g. bold errors and italic info. Pass [`Modifier::empty()`] for the
+    /// plain color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Modifier;
+    /// use ratatui_themes::{NotificationLevel, ThemeName};
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    /// let style = palette.semantic_style(
+    ///     NotificationLevel::Error,
+    ///     Modifier::BOLD | Modifier::UNDERLINED,
+    /// );
+    ///
+    /// assert_eq!(style.fg, Some(palette.error));
+    /// assert!(style.add_modifier.contains(Modifier::BOLD));
+    /// ```
+    #[must_use]
+    pub const fn semantic_style(&self, level: NotificationLevel, modifiers: Modifier) -> Style {
+        Style::new()
+            .fg(self.level_color(level))
+            .add_modifier(modifiers)
+    }
+
+    fg_styles! {
+        /// Returns a style with the `error` foreground.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use ratatui::{style::Style, text::Span};
+        /// use ratatui_themes::ThemeName;
+        ///
+        /// let palette = ThemeName::Dracula.palette();
+        /// let message = Span::styled("Upload failed", palette.error_style());
+        /// assert_eq!(message.style, Style::default().fg(palette.error));
+        /// ```
+        error_style => error,
+        /// Returns a style with the `warning` foreground.
+        warning_style => warning,
+        /// Returns a style with the `success` foreground.
+        success_style => success,
+        /// Returns a style with the `info` foreground.
+        info_style => info,
+        /// Returns a style with the `muted` foreground, e.g. for comments and hints.
+        muted_style => muted,
+        /// Returns a style with the `accent` foreground.
+        accent_style => accent,
+    }
+
+    /// Returns the style for a selected row: `fg` text on the `selection` background.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem};
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    /// let list = List::new([ListItem::new("Inbox"), ListItem::new("Drafts")])
+    ///     .highlight_style(palette.selected_style());
+    /// ```
+    #[must_use]
+    pub const fn selected_style(&self) -> Style {
+        Style::new().fg(self.fg).bg(self.selection)
+    }
+
+    /// Returns the style for titles and headings: bold `accent`.
+    ///
+    /// This matches the block titles drawn by the `ThemePicker` widget.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{style::Modifier, widgets::Block};
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    /// let block = Block::bordered().title_style(palette.title_style());
+    /// assert!(palette.title_style().add_modifier.contains(Modifier::BOLD));
+    /// ```
+    #[must_use]
+    pub const fn title_style(&self) -> Style {
+        Style::new().fg(self.accent).add_modifier(Modifier::BOLD)
+    }
+
+    /// Default glyph used by [`status_indicator()`](Self::status_indicator).
+    #[cfg(feature = "widgets")]
+    pub const STATUS_GLYPH: &'static str = "●";
+
+    /// Returns a one-character status indicator in the level's semantic color.
+    ///
+    /// The span contains [`STATUS_GLYPH`](Self::STATUS_GLYPH) (`●`); use
+    /// [`status_indicator_with()`](Self::status_indicator_with) for another
+    /// glyph.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::text::Line;
+    /// use ratatui_themes::{NotificationLevel, ThemeName};
+    ///
+    /// let palette = ThemeName::TokyoNight.palette();
+    /// let row = Line::from(vec![
+    ///     palette.status_indicator(NotificationLevel::Success),
+    ///     " api-server".into(),
+    /// ]);
+    /// assert_eq!(row.spans[0].style.fg, Some(palette.success));
+    /// ```
+    #[cfg(feature = "widgets")]
+    #[must_use]
+    pub fn status_indicator(&self, level: NotificationLevel) -> ratatui::text::Span<'static> {
+        self.status_indicator_with(level, Self::STATUS_GLYPH)
+    }
+
+    /// Like [`status_indicator()`](Self::status_indicator), with a custom glyph.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{NotificationLevel, ThemeName};
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    /// let span = palette.status_indicator_with(NotificationLevel::Warning, "▲");
+    /// assert_eq!(span.content, "▲");
+    /// ```
+    #[cfg(feature = "widgets")]
+    #[must_use]
+    pub fn status_indicator_with(
+        &self,
+        level: NotificationLevel,
+        glyph: &'static str,
+    ) -> ratatui::text::Span<'static> {
+        ratatui::text::Span::styled(glyph, Style::new().fg(self.level_color(level)))
+    }
+
+    /// Minimum `fg`-on-`bg` contrast accepted by [`validate()`](Self::validate).
+    ///
+    /// This is the WCAG threshold for large text; anything lower is hard to
+    /// read at any size.
+    pub const MIN_TEXT_CONTRAST: f64 = 3.0;
+
+    /// Minimum contrast against `bg` for a color to count as visible in
+    /// [`validate()`](Self::validate).
+    pub const MIN_VISIBLE_CONTRAST: f64 = 1.5;
+
+    /// Check that the palette is usable, e.g. right after deserializing it.
+    ///
+    /// The checks run in this order and the first failure is returned:
+    ///
+    /// 1. `fg` and `bg` differ ([`SameForegroundAndBackground`](PaletteValidationError::SameForegroundAndBackground)).
+    ///    Both being [`Color::Reset`] is allowed: that is the terminal's own
+    ///    default foreground on its default background.
+    /// 2. `fg` reaches [`MIN_TEXT_CONTRAST`](Self::MIN_TEXT_CONTRAST) against
+    ///    `bg` ([`LowTextContrast`](PaletteValidationError::LowTextContrast)).
+    /// 3. `accent`, `error`, `warning`, `success` and `info` each reach
+    ///    [`MIN_VISIBLE_CONTRAST`](Self::MIN_VISIBLE_CONTRAST) against `bg`
+    ///    ([`InvisibleColor`](PaletteValidationError::InvisibleColor)).
+    ///
+    /// Contrast checks are skipped for colors without a known RGB value, such
+    /// as [`Color::Reset`], since their on-screen appearance depends on the
+    /// terminal. Every built-in theme passes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PaletteValidationError`] describing the first problem found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, PaletteValidationError, ThemeName};
+    ///
+    /// assert!(ThemeName::Nord.palette().validate().is_ok());
+    ///
+    /// let mut palette = ThemeName::Nord.palette();
+    /// palette.error = palette.bg;
+    /// assert!(matches!(
+    ///     palette.validate(),
+    ///     Err(PaletteValidationError::InvisibleColor { field: "error", .. })
+    /// ));
+    /// ```
+    pub fn validate(&self) -> Result<(), PaletteValidationError> {
+        // Two `Reset`s are the terminal's default foreground and background,
+        // which are distinct colors.
+        if self.fg == self.bg && self.bg != Color::Reset {
+            return Err(PaletteValidationError::SameForegroundAndBackground);
+        }
+        if let Some(ratio) = self.bg_contrast(self.fg) {
+            if ratio < Self::MIN_TEXT_CONTRAST {
+                return Err(PaletteValidationError::LowTextContrast { ratio });
+            }
+        }
+        for (field, c) in [
+            ("accent", self.accent),
+            ("error", self.error),
+            ("warning", self.warning),
+            ("success", self.success),
+            ("info", self.info),
+        ] {
+            if let Some(ratio) = self.bg_contrast(c) {
+                if ratio < Self::MIN_VISIBLE_CONTRAST {
+                    return Err(PaletteValidationError::InvisibleColor { field, ratio });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Default channel floor used by [`soften_extremes()`](Self::soften_extremes).
+    pub const SOFTEN_FLOOR: u8 = 10;
+
+    /// Default channel ceiling used by [`soften_extremes()`](Self::soften_extremes).
+    pub const SOFTEN_CEIL: u8 = 245;
+
+    /// Returns the in-palette colors that can stand in for [`accent`](Self::accent).
+    ///
+    /// Candidates are [`secondary`](Self::secondary), [`info`](Self::info),
+    /// [`success`](Self::success) and [`warning`](Self::warning), in that
+    /// order — the hues a theme already uses for highlights. [`error`](Self::error)
+    /// is left out because a red accent reads as an alarm, and the neutral
+    /// fields (`bg`, `fg`, `muted`, `selection`) are not accents at all.
+    ///
+    /// Candidates are dropped if they equal the current accent, repeat an
+    /// earlier candidate, or fall below 3:1 contrast against [`bg`](Self::bg)
+    /// (the WCAG minimum for UI components). The contrast check is skipped
+    /// when `bg` is [`Color::Reset`], whose value is unknown. Pair with
+    /// [`with_accent()`](Self::with_accent) to let users personalize a theme.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let palette = ThemeName::Dracula.palette();
+    /// let choices = palette.accent_alternatives();
+    /// assert_eq!(choices[0], palette.secondary);
+    ///
+    /// let green = palette.with_accent(palette.success);
+    /// assert_eq!(green.accent, palette.success);
+    /// ```
+    #[must_use]
+    pub fn accent_alternatives(&self) -> Vec<Color> {
+        let mut alternatives: Vec<Color> = Vec::with_capacity(4);
+        for candidate in [self.secondary, self.info, self.success, self.warning] {
+            if candidate != self.accent
+                && !alternatives.contains(&candidate)
+                && (self.bg == Color::Reset || color::contrast_ratio(candidate, self.bg) >= 3.0)
+            {
+                alternatives.push(candidate);
+            }
+        }
+        alternatives
+    }
+
+    /// Returns a color for keyboard focus rings that stays visible on `bg`.
+    ///
+    /// Returns [`accent`](Self::accent) when it reaches 3:1 contrast against
+    /// [`bg`](Self::bg) (the WCAG minimum for focus indicators). Otherwise the
+    /// highest-contrast of `accent`, [`secondary`](Self::secondary),
+    /// [`info`](Self::info) and [`fg`](Self::fg) is returned, preferring the
+    /// earlier one on ties. A [`Color::Reset`] background has no known
+    /// contrast, so `accent` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{style::Style, widgets::Block};
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    /// assert_eq!(palette.focus_ring_color(), palette.accent);
+    ///
+    /// // An accent that blends into the background falls back to a visible color.
+    /// let dim = palette.with_accent(Color::Rgb(0x3b, 0x42, 0x52));
+    /// assert_ne!(dim.focus_ring_color(), dim.accent);
+    ///
+    /// let focused = Block::bordered().border_style(Style::new().fg(dim.focus_ring_color()));
+    /// ```
+    #[must_use]
+    pub fn focus_ring_color(&self) -> Color {
+        if self
+            .bg_contrast(self.accent)
+            .map_or(true, |ratio| ratio >= 3.0)
+        {
+            return self.accent;
+        }
+        [self.accent, self.secondary, self.info, self.fg]
+            .into_iter()
+            .map(|c| (c, color::contrast_ratio(c, self.bg)))
+            .fold((self.accent, 0.0), |best, candidate| {
+                if candidate.1 > best.1 {
+                    candidate
+                } else {
+                    best
+                }
+            })
+            .0
+    }
+
+    /// Returns the accent's complementary color: its hue rotated by 180°.
+    ///
+    /// Saturation and lightness are kept, so the result sits at the same
+    /// intensity as [`accent`](Self::accent) while contrasting with it in hue,
+    /// e.g. for a "cancel" button next to an accent "confirm" button. The
+    /// color is derived, not chosen by the theme's designer, so it may clash
+    /// with the rest of the palette on some themes.
+    ///
+    /// Gray accents (HSL saturation below 0.1) have no meaningful hue, and a
+    /// [`Color::Reset`] accent has no value at all; both fall back to
+    /// [`secondary`](Self::secondary).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemePalette};
+    ///
+    /// let palette = ThemePalette::default().with_accent(Color::Rgb(255, 0, 0));
+    /// assert_eq!(palette.complementary_accent(), Color::Rgb(0, 255, 255));
+    ///
+    /// let gray = palette.with_accent(Color::Rgb(128, 128, 128));
+    /// assert_eq!(gray.complementary_accent(), gray.secondary);
+    /// ```
+    #[must_use]
+    pub fn complementary_accent(&self) -> Color {
+        /// HSL saturation below which the accent is treated as gray.
+        const MIN_SATURATION: f32 = 0.1;
+
+        match color::to_rgb(self.accent).map(|(r, g, b)| color::rgb_to_hsl(r, g, b)) {
+            Some((h, s, l)) if s >= MIN_SATURATION => color::hsl_to_rgb(h + 180.0, s, l),
+            _ => self.secondary,
+        }
+    }
+
+    /// Returns a copy of the palette with a different [`accent`](Self::accent).
+    ///
+    /// See [`accent_alternatives()`](Self::accent_alternatives) for colors that
+    /// stay within the theme's harmony.
+    #[must_use]
+    pub const fn with_accent(mut self, accent: Color) -> Self {
+        self.accent = accent;
+        self
+    }
+
+    /// Returns a copy that draws on the terminal's own background.
+    ///
+    /// Sets [`bg`](Self::bg) to [`Color::Reset`], keeping every other color,
+    /// for apps that want the theme's accents over the user's terminal
+    /// background (including transparent terminals). The helpers treat a
+    /// `Reset` color as unknown rather than as black:
+    ///
+    /// - [`is_light()`](Self::is_light) reports the palette as dark; use
+    ///   [`is_light_with_terminal()`](Self::is_light_with_terminal) when the
+    ///   terminal background is known.
+    /// - [`validate()`](Self::validate) and
+    ///   [`accent_alternatives()`](Self::accent_alternatives) skip contrast
+    ///   checks against the unknown background.
+    /// - The `on_*` helpers return `Reset` (the terminal's default foreground)
+    ///   for text drawn on a `Reset` color.
+    /// - Styles built from the palette pass `Reset` through, leaving that
+    ///   side of the cell to the terminal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let palette = ThemeName::TokyoNight.palette().transparent_bg();
+    /// assert_eq!(palette.bg, Color::Reset);
+    /// assert_eq!(palette.accent, ThemeName::TokyoNight.palette().accent);
+    /// assert!(palette.validate().is_ok());
+    /// ```
+    #[must_use]
+    pub const fn transparent_bg(mut self) -> Self {
+        self.bg = Color::Reset;
+        self
+    }
+
+    /// Returns a copy with near-black and near-white colors pulled toward the middle.
+    ///
+    /// Pure `#000000` backgrounds look harsh on OLED screens and hide dark
+    /// borders, while pure `#ffffff` can glare. This lifts any color whose
+    /// channels are *all* below `floor` up to `floor`, and caps any color whose
+    /// channels are *all* above `ceil` down to `ceil`. Everything else —
+    /// including saturated colors such as neon cyan `(0, 255, 255)` — is left
+    /// untouched. Named and indexed colors are resolved to RGB first (see
+    /// [`resolve_rgb()`](Self::resolve_rgb)); [`Color::Reset`] is left as is.
+    ///
+    /// [`SOFTEN_FLOOR`](Self::SOFTEN_FLOOR) (10) and
+    /// [`SOFTEN_CEIL`](Self::SOFTEN_CEIL) (245) are sensible defaults.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemePalette};
+    ///
+    /// let mut palette = ThemePalette::default();
+    /// palette.bg = Color::Rgb(0, 0, 0);
+    /// palette.fg = Color::Rgb(255, 255, 255);
+    ///
+    /// let soft = palette.soften_extremes(ThemePalette::SOFTEN_FLOOR, ThemePalette::SOFTEN_CEIL);
+    /// assert_eq!(soft.bg, Color::Rgb(10, 10, 10));
+    /// assert_eq!(soft.fg, Color::Rgb(245, 245, 245));
+    /// assert_eq!(soft.accent, palette.accent);
+    /// ```
+    #[must_use]
+    pub fn soften_extremes(&self, floor: u8, ceil: u8) -> Self {
+        self.map(|c| {
+            color::map_rgb(c, |r, g, b| {
+                if r < floor && g < floor && b < floor {
+                    (floor, floor, floor)
+                } else if r > ceil && g > ceil && b > ceil {
+                    (ceil, ceil, ceil)
+                } else {
+                    (r, g, b)
+                }
+            })
+        })
+    }
+
+    /// Returns a copy with every RGB channel offset by the given deltas.
+    ///
+    /// Each channel is shifted by its signed delta and clamped to `0..=255`,
+    /// so pushing red up and blue down gives a warmer variant of any theme.
+    /// Named and indexed colors are resolved to RGB first (see
+    /// [`resolve_rgb()`](Self::resolve_rgb)); [`Color::Reset`] is passed
+    /// through unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    /// let warmer = palette.tint(12, 0, -12);
+    ///
+    /// assert_eq!(palette.bg, Color::Rgb(46, 52, 64));
+    /// assert_eq!(warmer.bg, Color::Rgb(58, 52, 52));
+    ///
+    /// // Channels clamp instead of wrapping.
+    /// assert_eq!(palette.tint(300, -300, 0).bg, Color::Rgb(255, 0, 64));
+    /// ```
+    #[must_use]
+    pub fn tint(&self, dr: i16, dg: i16, db: i16) -> Self {
+        let shift = |channel: u8, delta: i16| {
+            u8::try_from((i16::from(channel) + delta).clamp(0, 255)).unwrap_or(u8::MAX)
+        };
+        self.map(|c| color::map_rgb(c, |r, g, b| (shift(r, dr), shift(g, dg), shift(b, db))))
+    }
+
+    /// Returns a warmer copy with less blue, like a screen's night-light mode.
+    ///
+    /// At `strength` 1.0 every color keeps 40% of its blue channel and gains
+    /// 10% red; lower strengths scale both changes linearly, and 0.0 leaves
+    /// the palette unchanged. `strength` is clamped to `0.0..=1.0` and
+    /// channels to `0..=255`. This is a uniform per-channel warmth shift
+    /// that reads as a lower color temperature, not a true blackbody
+    /// white-point transform. Named and indexed colors are resolved to RGB
+    /// first (see [`resolve_rgb()`](Self::resolve_rgb)); [`Color::Reset`] is
+    /// passed through unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    /// let evening = palette.reduce_blue(1.0);
+    ///
+    /// assert_eq!(palette.info, Color::Rgb(94, 129, 172));
+    /// assert_eq!(evening.info, Color::Rgb(103, 129, 69));
+    /// assert_eq!(palette.reduce_blue(0.0), palette);
+    /// ```
+    #[must_use]
+    pub fn reduce_blue(&self, strength: f32) -> Self {
+        /// Share of the blue channel removed at full strength.
+        const BLUE_CUT: f32 = 0.6;
+        /// Relative red boost at full strength.
+        const RED_BOOST: f32 = 0.1;
+
+        let strength = strength.clamp(0.0, 1.0);
+        let red = RED_BOOST.mul_add(strength, 1.0);
+        let blue = (-BLUE_CUT).mul_add(strength, 1.0);
+        self.map(|c| {
+            color::map_rgb(c, |r, g, b| {
+                (
+                    color::channel(f32::from(r) * red),
+                    g,
+                    color::channel(f32::from(b) * blue),
+                )
+            })
+        })
+    }
+
+    /// Returns a copy with every color snapped to the nearest of `palette`.
+    ///
+    /// Use this when the terminal is locked to a custom set of colors (such
+    /// as its configured 16-color palette): pass those colors and every
+    /// field is replaced by the candidate closest in RGB space, so the theme
+    /// renders within them. The chosen candidate is returned exactly as
+    /// given, so passing [`Color::Indexed`] slots keeps the result tied to
+    /// the terminal's own palette.
+    ///
+    /// Named and indexed candidates and fields are compared by their
+    /// standard RGB values (see [`resolve_rgb()`](Self::resolve_rgb)).
+    /// Candidates without a fixed value, such as [`Color::Reset`], are never
+    /// chosen, and [`Color::Reset`] fields are passed through unchanged.
+    /// Ties go to the earlier candidate. If `palette` has no RGB-resolvable
+    /// colors, the palette is returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let terminal = [
+    ///     Color::Rgb(20, 20, 20),
+    ///     Color::Rgb(230, 230, 230),
+    ///     Color::Rgb(220, 50, 50),
+    ///     Color::Rgb(80, 200, 80),
+    /// ];
+    /// let snapped = ThemeName::Dracula.palette().quantize_to(&terminal);
+    ///
+    /// assert_eq!(snapped.bg, Color::Rgb(20, 20, 20));
+    /// assert_eq!(snapped.fg, Color::Rgb(230, 230, 230));
+    /// assert_eq!(snapped.error, Color::Rgb(220, 50, 50));
+    /// assert_eq!(snapped.success, Color::Rgb(80, 200, 80));
+    /// ```
+    #[must_use]
+    pub fn quantize_to(&self, palette: &[Color]) -> Self {
+        let candidates: Vec<(Color, (u8, u8, u8))> = palette
+            .iter()
+            .filter_map(|&c| color::to_rgb(c).map(|rgb| (c, rgb)))
+            .collect();
+        self.map(|c| {
+            color::to_rgb(c)
+                .and_then(|rgb| {
+                    candidates
+                        .iter()
+                        .min_by_key(|(_, candidate)| color::distance_sq(rgb, *candidate))
+                })
+                .map_or(c, |&(candidate, _)| candidate)
+        })
+    }
+
+    /// Returns the channel-wise average of several palettes.
+    ///
+    /// Each field is the mean of that field's RGB channels across
+    /// `palettes`, so averaging Nord and Dracula gives an in-between hybrid.
+    /// Named and indexed colors are resolved to RGB first (see
+    /// [`resolve_rgb()`](Self::resolve_rgb)). Colors without a fixed value,
+    /// such as [`Color::Reset`], are skipped; a field that is non-RGB in
+    /// every palette keeps the first palette's value. An empty slice returns
+    /// [`ThemePalette::default()`].
+    ///
+    /// For weighted blending of built-in themes, see
+    /// [`ThemeName::blend_themes()`](crate::ThemeName::blend_themes).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName, ThemePalette};
+    ///
+    /// let hybrid = ThemePalette::average(&[
+    ///     ThemeName::Nord.palette(),
+    ///     ThemeName::Dracula.palette(),
+    /// ]);
+    ///
+    /// // Nord's (46, 52, 64) and Dracula's (40, 42, 54).
+    /// assert_eq!(hybrid.bg, Color::Rgb(43, 47, 59));
+    /// assert_eq!(ThemePalette::average(&[]), ThemePalette::default());
+    /// ```
+    #[must_use]
+    pub fn average(palettes: &[Self]) -> Self {
+        let weighted: Vec<(Self, f32)> = palettes.iter().map(|&p| (p, 1.0)).collect();
+        Self::weighted_average(&weighted)
+    }
+
+    /// Channel-wise weighted average of palettes.
+    ///
+    /// Non-RGB colors and non-positive weights are skipped per field; a
+    /// field with nothing left keeps the first palette's value. An empty
+    /// slice returns the default palette.
+    pub(crate) fn weighted_average(palettes: &[(Self, f32)]) -> Self {
+        let Some(&(first, _)) = palettes.first() else {
+            return Self::default();
+        };
+        let field = |get: fn(&Self) -> Color| {
+            let mut sums = [0.0f32; 3];
+            let mut total = 0.0f32;
+            for (palette, weight) in palettes {
+                let Some(rgb) = color::to_rgb(get(palette)) else {
+                    continue;
+                };
+                if *weight > 0.0 {
+                    for (sum, c) in sums.iter_mut().zip(<[u8; 3]>::from(rgb)) {
+                        *sum = weight.mul_add(f32::from(c), *sum);
+                    }
+                    total += weight;
+                }
+            }
+            if total > 0.0 {
+                let [r, g, b] = sums.map(|sum| color::channel(sum / total));
+                Color::Rgb(r, g, b)
+            } else {
+                get(&first)
+            }
+        };
+
+        Self {
+            accent: field(|p| p.accent),
+            secondary: field(|p| p.secondary),
+            bg: field(|p| p.bg),
+            fg: field(|p| p.fg),
+            muted: field(|p| p.muted),
+            selection: field(|p| p.selection),
+            error: field(|p| p.error),
+            warning: field(|p| p.warning),
+            success: field(|p| p.success),
+            info: field(|p| p.info),
+        }
+    }
+
+    /// Apply `f` to every color in the palette.
+    fn map(&self, f: impl Fn(Color) -> Color) -> Self {
+        Self {
+            accent: f(self.accent),
+            secondary: f(self.secondary),
+            bg: f(self.bg),
+            fg: f(self.fg),
+            muted: f(self.muted),
+            selection: f(self.selection),
+            error: f(self.error),
+            warning: f(self.warning),
+            success: f(self.success),
+            info: f(self.info),
+        }
+    }
+
+    /// WCAG contrast ratio of `color` against [`bg`](Self::bg), or `None`
+    /// if either has no known RGB value (such as [`Color::Reset`]).
+    fn bg_contrast(&self, color: Color) -> Option<f64> {
+        match (color::to_rgb(color), color::to_rgb(self.bg)) {
+            (Some(_), Some(_)) => Some(color::contrast_ratio(color, self.bg)),
+            _ => None,
+        }
+    }
+
+    /// Pick a text color for `background`, preferring the palette's own colors.
+    fn text_on(&self, background: Color) -> Color {
+        if background == Color::Reset {
+            return Color::Reset;
+        }
+        let by_contrast = |a: &Color, b: &Color| {
+            color::contrast_ratio(*a, background).total_cmp(&color::contrast_ratio(*b, background))
+        };
+
+        let themed = [self.bg, self.fg]
+            .into_iter()
+            .max_by(by_contrast)
+            .unwrap_or(self.fg);
+        if color::contrast_ratio(themed, background) >= 4.5 {
+            return themed;
+        }
+
+        [themed, Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)]
+            .into_iter()
+            .max_by(by_contrast)
+            .unwrap_or(themed)
+    }
+}
+
+impl Default for ThemePalette {
+    /// Returns the default palette (Dracula theme).
+    fn default() -> Self {
+        crate::ThemeName::default().palette()
+    }
+}
+
+/// Severity of a status or notification, mapped to the palette's semantic colors.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_themes::{NotificationLevel, ThemeName};
+///
+/// let palette = ThemeName::Nord.palette();
+/// assert_eq!(palette.level_color(NotificationLevel::Error), palette.error);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum NotificationLevel {
+    /// Neutral information, drawn with [`info`](ThemePalette::info).
+    Info,
+    /// A completed or healthy state, drawn with [`success`](ThemePalette::success).
+    Success,
+    /// Something needs attention, drawn with [`warning`](ThemePalette::warning).
+    Warning,
+    /// A failure, drawn with [`error`](ThemePalette::error).
+    Error,
+}
+
+/// A problem found by [`ThemePalette::validate`] or when building a palette
+/// with [`ThemePaletteBuilder`](crate::ThemePaletteBuilder).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PaletteValidationError {
+    /// A builder was finished without setting this color.
+    MissingField {
+        /// Name of the unset palette field (e.g. `"bg"`).
+        field: &'static str,
+    },
+    /// `fg` and `bg` are the same color, so text is invisible.
+    SameForegroundAndBackground,
+    /// `fg` contrasts too little with `bg` to be readable.
+    LowTextContrast {
+        /// The WCAG contrast ratio of `fg` against `bg`.
+        ratio: f64,
+    },
+    /// A color meant to stand out is nearly invisible against `bg`.
+    InvisibleColor {
+        /// Name of the offending palette field (e.g. `"error"`).
+        field: &'static str,
+        /// The WCAG contrast ratio of the field against `bg`.
+        ratio: f64,
+    },
+}
+
+impl core::fmt::Display for PaletteValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingField { field } => write!(f, "Palette {field} color is not set"),
+            Self::SameForegroundAndBackground => {
+                write!(f, "Palette fg and bg are the same color")
+            }
+            Self::LowTextContrast { ratio } => write!(
+                f,
+                "Palette fg contrast against bg is {ratio:.2}:1, below the minimum of {:.1}:1",
+                ThemePalette::MIN_TEXT_CONTRAST
+            ),
+            Self::InvisibleColor { field, ratio } => write!(
+                f,
+                "Palette {field} is nearly invisible against bg ({ratio:.2}:1)"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PaletteValidationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ThemeName;
+
+    #[test]
+    fn test_on_colors_are_legible() {
+        for theme in ThemeName::all() {
+            let palette = theme.palette();
+            for (background, text) in [
+                (palette.accent, palette.on_accent()),
+                (palette.error, palette.on_error()),
+                (palette.success, palette.on_success()),
+                (palette.selection, palette.on_selection()),
+            ] {
+                assert!(
+                    color::contrast_ratio(text, background) >= 3.0,
+                    "{theme}: {text:?} on {background:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fields_by_luminance_order() {
+        let mut palette = ThemeName::Nord.palette();
+        palette.muted = Color::Reset;
+
+        let sorted = palette.fields_by_luminance();
+        assert_eq!(sorted.len(), 10);
+        assert_eq!(sorted.last(), Some(&("muted", Color::Reset)));
+
+        let luminances: Vec<f64> = sorted[..9]
+            .iter()
+            .filter_map(|(_, c)| color::luminance(*c))
+            .collect();
+        assert!(luminances.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn test_soften_extremes_only_touches_extremes() {
+        let palette = ThemeName::Cyberpunk.palette();
+        let soft = palette.soften_extremes(20, 235);
+
+        // Near-black bg (13, 2, 33) is not uniformly below 20, so it stays.
+        assert_eq!(soft.bg, palette.bg);
+        // Neon cyan has a zero channel but is not near-black.
+        assert_eq!(soft.accent, palette.accent);
+        // Near-white fg (240, 240, 240) is capped.
+        assert_eq!(soft.fg, Color::Rgb(235, 235, 235));
+    }
+
+    #[test]
+    fn test_from_ansi16_named_colors() {
+        let palette = ThemePalette::from_ansi16([
+            Color::Black,
+            Color::Red,
+            Color::Green,
+            Color::Yellow,
+            Color::Blue,
+            Color::Magenta,
+            Color::Cyan,
+            Color::Gray,
+            Color::DarkGray,
+            Color::LightRed,
+            Color::LightGreen,
+            Color::LightYellow,
+            Color::LightBlue,
+            Color::LightMagenta,
+            Color::LightCyan,
+            Color::White,
+        ]);
+        assert_eq!(palette.bg, Color::Black);
+        assert_eq!(palette.fg, Color::Gray);
+        assert_eq!(palette.accent, Color::LightBlue);
+        assert_eq!(palette.selection, Color::Rgb(64, 64, 64));
+    }
+
+    #[test]
+    fn test_ansi16_maps_semantic_colors() {
+        let palette = ThemeName::Dracula.palette();
+        let ansi = palette.ansi16();
+        assert_eq!(ansi[1], palette.error);
+        assert_eq!(ansi[0], palette.bg);
+        assert_eq!(ansi[7], palette.fg);
+        assert_eq!(ansi[8], palette.muted);
+        assert_eq!(
+            ansi[9],
+            palette.error.lighten(ThemePalette::ANSI_BRIGHT_STEP)
+        );
+        assert_ne!(ansi[9], ansi[1]);
+
+        for &theme in ThemeName::all() {
+            let palette = theme.palette();
+            let back = ThemePalette::from_ansi16(palette.ansi16());
+            let back = ThemePalette {
+                selection: palette.selection,
+                ..back
+            };
+            assert_eq!(back, palette, "{theme}");
+        }
+    }
+
+    #[test]
+    fn test_from_base16_default_dark() {
+        // base16 "Default Dark" by Chris Kempson
+        let scheme = [
+            "181818", "282828", "383838", "585858", "b8b8b8", "d8d8d8", "e8e8e8", "f8f8f8",
+            "ab4642", "dc9656", "f7ca88", "a1b56c", "86c1b9", "7cafc2", "ba8baf", "a16946",
+        ];
+        let palette = ThemePalette::from_base16_hex(&scheme).unwrap();
+        assert_eq!(palette.bg, Color::Rgb(0x18, 0x18, 0x18));
+        assert_eq!(palette.fg, Color::Rgb(0xd8, 0xd8, 0xd8));
+        assert_eq!(palette.error, Color::Rgb(0xab, 0x46, 0x42));
+        assert_eq!(palette.warning, Color::Rgb(0xf7, 0xca, 0x88));
+        assert_eq!(palette.success, Color::Rgb(0xa1, 0xb5, 0x6c));
+        assert_eq!(palette.accent, Color::Rgb(0x7c, 0xaf, 0xc2));
+        assert!(palette.is_dark());
+        assert_eq!(palette.validate(), Ok(()));
+
+        let colors = scheme.map(|hex| ThemePalette::from_hex(hex).unwrap());
+        assert_eq!(ThemePalette::from_base16(colors), palette);
+        assert_eq!(
+            ThemePalette::from_base16_hex(&["#fff"; 16]).unwrap().bg,
+            Color::Rgb(255, 255, 255)
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable_and_distinct() {
+        // Pinned so an accidental change to the hash is caught.
+        assert_eq!(ThemeName::Dracula.fingerprint(), 0x8556_510c_38d2_bd75);
+
+        let mut fingerprints: Vec<u64> = ThemeName::all().iter().map(|t| t.fingerprint()).collect();
+        fingerprints.sort_unstable();
+        fingerprints.dedup();
+        assert_eq!(fingerprints.len(), ThemeName::all().len());
+
+        for &theme in ThemeName::all() {
+            assert_eq!(theme.palette().matches_builtin(), Some(theme));
+        }
+    }
+
+    #[test]
+    fn test_tint_resolves_named_and_passes_through_reset() {
+        let mut palette = ThemeName::Dracula.palette();
+        palette.muted = Color::DarkGray;
+        palette.bg = Color::Reset;
+
+        let tinted = palette.tint(10, 10, 10);
+        assert_eq!(tinted.muted, Color::Rgb(137, 137, 137));
+        assert_eq!(tinted.bg, Color::Reset);
+        assert_eq!(tinted.fg, Color::Rgb(255, 255, 252));
+    }
+
+    #[test]
+    fn test_builtin_themes_are_not_monochrome() {
+        for theme in ThemeName::all() {
+            assert!(!theme.palette().is_monochrome(16), "{theme}");
+        }
+    }
+
+    #[test]
+    fn test_accent_alternatives_are_visible_and_unique() {
+        for theme in ThemeName::all() {
+            let palette = theme.palette();
+            let alternatives = palette.accent_alternatives();
+            for (i, &alt) in alternatives.iter().enumerate() {
+                assert_ne!(alt, palette.accent);
+                assert!(!alternatives[..i].contains(&alt));
+                assert!(color::contrast_ratio(alt, palette.bg) >= 3.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_contrast_matrix_is_symmetric() {
+        for theme in ThemeName::all() {
+            let matrix = theme.palette().contrast_matrix();
+            for (i, row) in matrix.iter().enumerate() {
+                assert!((row[i] - 1.0).abs() < f64::EPSILON);
+                for (j, &ratio) in row.iter().enumerate() {
+                    assert!((ratio - matrix[j][i]).abs() < f64::EPSILON);
+                    assert!((1.0..=21.0).contains(&ratio));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_distinct_colors_generates_beyond_palette() {
+        for theme in ThemeName::all() {
+            let palette = theme.palette();
+            assert!(palette.distinct_colors(0).is_empty());
+
+            let colors = palette.distinct_colors(24);
+            assert_eq!(colors.len(), 24);
+            for (i, c) in colors.iter().enumerate() {
+                assert!(!colors[..i].contains(c), "{theme}: repeated {c:?}");
+            }
+            assert_eq!(palette.distinct_colors(3), colors[..3]);
+        }
+    }
+
+    #[test]
+    fn test_distinct_colors_large_n_terminates() {
+        for theme in [
+            ThemeName::Nord,
+            ThemeName::Dracula,
+            ThemeName::SolarizedLight,
+        ] {
+            let palette = theme.palette();
+            let many = palette.distinct_colors(3000);
+            assert_eq!(many.len(), 3000, "{theme}");
+            assert_eq!(many[..24], palette.distinct_colors(24), "{theme}");
+
+            let unique: Vec<Color> = palette.distinct_colors(500);
+            for (i, c) in unique.iter().enumerate() {
+                assert!(!unique[..i].contains(c), "{theme}: repeated {c:?}");
+            }
+        }
+        assert_eq!(ThemeName::Nord.palette().distinct_colors(1000).len(), 1000);
+    }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        for (r, g, b) in [(189, 147, 249), (255, 0, 0), (40, 42, 54), (0, 128, 64)] {
+            let (h, s, l) = color::rgb_to_hsl(r, g, b);
+            assert_eq!(color::hsl_to_rgb(h, s, l), Color::Rgb(r, g, b));
+        }
+    }
+
+    #[test]
+    fn test_relative_luminance_matches_wcag() {
+        assert!((ThemePalette::relative_luminance(Color::Rgb(255, 0, 0)) - 0.2126).abs() < 1e-9);
+        assert!(
+            (ThemePalette::relative_luminance(Color::Rgb(128, 128, 128)) - 0.2159).abs() < 1e-4
+        );
+        assert!(ThemePalette::relative_luminance(Color::Reset).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_is_light_wcag_agrees_for_builtins() {
+        for theme in ThemeName::all() {
+            let palette = theme.palette();
+            assert_eq!(palette.is_light_wcag(), palette.is_light(), "{theme}");
+        }
+    }
+
+    #[test]
+    fn test_transforms_resolve_named_and_indexed_colors() {
+        let palette = ThemePalette {
+            bg: Color::Black,
+            fg: Color::Indexed(231),    // cube white
+            muted: Color::Indexed(244), // gray ramp
+            accent: Color::Reset,
+            ..ThemePalette::default()
+        };
+
+        let soft = palette.soften_extremes(ThemePalette::SOFTEN_FLOOR, ThemePalette::SOFTEN_CEIL);
+        assert_eq!(soft.bg, Color::Rgb(10, 10, 10));
+        assert_eq!(soft.fg, Color::Rgb(245, 245, 245));
+        assert_eq!(soft.muted, Color::Indexed(244));
+        assert_eq!(soft.accent, Color::Reset);
+
+        let tinted = palette.tint(10, 0, 0);
+        assert_eq!(tinted.bg, Color::Rgb(10, 0, 0));
+        assert_eq!(tinted.muted, Color::Rgb(138, 128, 128));
+        assert_eq!(tinted.accent, Color::Reset);
+        assert_eq!(palette.tint(0, 0, 0), palette);
+
+        assert_eq!(
+            color::blend(Color::Black, Color::White, 0.5),
+            Color::Rgb(128, 128, 128)
+        );
+        assert_eq!(color::blend(Color::Red, Color::Reset, 0.5), Color::Red);
+        assert_eq!(color::blend(Color::Reset, Color::Red, 0.5), Color::Reset);
+    }
+
+    #[test]
+    fn test_validate_reports_specific_problems() {
+        for theme in ThemeName::all() {
+            assert_eq!(theme.palette().validate(), Ok(()), "{theme}");
+        }
+
+        let dracula = ThemeName::Dracula.palette();
+        let same = ThemePalette {
+            fg: dracula.bg,
+            ..dracula
+        };
+        assert_eq!(
+            same.validate(),
+            Err(PaletteValidationError::SameForegroundAndBackground)
+        );
+
+        let dim = ThemePalette {
+            fg: dracula.selection,
+            ..dracula
+        };
+        assert!(matches!(
+            dim.validate(),
+            Err(PaletteValidationError::LowTextContrast { .. })
+        ));
+
+        let terminal_default = ThemePalette {
+            bg: Color::Reset,
+            ..dracula
+        };
+        assert_eq!(terminal_default.validate(), Ok(()));
+    }
+
+    #[cfg(feature = "widgets")]
+    #[test]
+    fn test_status_indicator_uses_semantic_colors() {
+        let palette = ThemeName::GruvboxDark.palette();
+        for (level, expected) in [
+            (NotificationLevel::Info, palette.info),
+            (NotificationLevel::Success, palette.success),
+            (NotificationLevel::Warning, palette.warning),
+            (NotificationLevel::Error, palette.error),
+        ] {
+            let span = palette.status_indicator(level);
+            assert_eq!(span.content, "●");
+            assert_eq!(span.style.fg, Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_for_eink_uses_four_levels() {
+        for theme in ThemeName::all() {
+            let palette = theme.palette();
+            let eink = palette.for_eink();
+
+            assert!(eink.is_monochrome(0));
+            assert_eq!(eink.is_light(), palette.is_light(), "{theme}");
+            assert_eq!(eink.contrast_grade(), ContrastGrade::Aaa);
+            for (_, c) in eink.colors() {
+                let (r, _, _) = ThemePalette::resolve_rgb(c);
+                assert!([0, 85, 170, 255].contains(&r), "{theme}: {c:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_nearest_theme_finds_tweaked_builtin() {
+        for &theme in ThemeName::all() {
+            let (nearest, distance) = theme.palette().nearest_theme();
+            assert_eq!(nearest, theme);
+            assert!(distance.abs() < f64::EPSILON);
+        }
+
+        let tweaked = ThemeName::Nord.palette().tint(6, -4, 3);
+        assert_eq!(tweaked.nearest_theme().0, ThemeName::Nord);
+    }
+
+    #[test]
+    fn test_semantic_style_combines_color_and_modifiers() {
+        let palette = ThemeName::RosePine.palette();
+        let plain = palette.semantic_style(NotificationLevel::Info, Modifier::empty());
+        assert_eq!(plain, Style::new().fg(palette.info));
+
+        let italic = palette.semantic_style(NotificationLevel::Info, Modifier::ITALIC);
+        assert_eq!(italic, plain.add_modifier(Modifier::ITALIC));
+    }
+
+    #[test]
+    fn test_style_helpers_match_hand_built_styles() {
+        for &theme in ThemeName::all() {
+            let palette = theme.palette();
+            assert_eq!(palette.error_style(), Style::default().fg(palette.error));
+            assert_eq!(
+                palette.warning_style(),
+                Style::default().fg(palette.warning)
+            );
+            assert_eq!(
+                palette.success_style(),
+                Style::default().fg(palette.success)
+            );
+            assert_eq!(palette.info_style(), Style::default().fg(palette.info));
+            assert_eq!(palette.muted_style(), Style::default().fg(palette.muted));
+            assert_eq!(palette.accent_style(), Style::default().fg(palette.accent));
+            assert_eq!(
+                palette.selected_style(),
+                Style::default().fg(palette.fg).bg(palette.selection)
+            );
+            assert_eq!(
+                palette.title_style(),
+                Style::default().fg(palette.accent).bold()
+            );
+        }
+    }
+
+    #[test]
+    fn test_zebra_backgrounds_are_distinct_on_every_theme() {
+        for &theme in ThemeName::all() {
+            let palette = theme.palette();
+            let (even, odd) = palette.zebra_backgrounds();
+            assert_eq!(even, palette.bg);
+            assert_ne!(odd, even, "{theme}");
+
+            let (bg, stripe) = (
+                ThemePalette::relative_luminance(even),
+                ThemePalette::relative_luminance(odd),
+            );
+            if palette.is_light() {
+                assert!(stripe < bg, "{theme}");
+            } else {
+                assert!(stripe > bg, "{theme}");
+            }
+        }
+
+        let custom = ThemePalette {
+            bg: Color::Reset,
+            ..ThemePalette::default()
+        };
+        assert_eq!(custom.zebra_backgrounds(), (Color::Reset, Color::Reset));
+    }
+
+    #[test]
+    fn test_new_follows_field_order() {
+        for &theme in ThemeName::all() {
+            let p = theme.palette();
+            let rebuilt = ThemePalette::new(
+                p.accent,
+                p.secondary,
+                p.bg,
+                p.fg,
+                p.muted,
+                p.selection,
+                p.error,
+                p.warning,
+                p.success,
+                p.info,
+            );
+            assert_eq!(rebuilt, p);
+        }
+    }
+
+    #[test]
+    fn test_transparent_bg_helpers_treat_reset_as_unknown() {
+        for &theme in ThemeName::all() {
+            let opaque = theme.palette();
+            let palette = opaque.transparent_bg();
+            assert_eq!(palette.bg, Color::Reset);
+            assert_eq!(palette.validate(), Ok(()), "{theme}");
+            assert!(!palette.is_light());
+            assert!(!palette.accent_alternatives().is_empty(), "{theme}");
+            assert_ne!(palette.on_accent(), Color::Reset);
+            assert_eq!(palette.accent, opaque.accent);
+        }
+
+        let both_reset = ThemePalette {
+            fg: Color::Reset,
+            ..ThemePalette::default().transparent_bg()
+        };
+        assert_eq!(both_reset.validate(), Ok(()));
+
+        let reset_accent = ThemePalette::default().with_accent(Color::Reset);
+        assert_eq!(reset_accent.on_accent(), Color::Reset);
+    }
+
+    #[test]
+    fn test_focus_ring_color_stays_visible() {
+        for &theme in ThemeName::all() {
+            let palette = theme.palette();
+            let ring = palette.focus_ring_color();
+            assert!(color::contrast_ratio(ring, palette.bg) >= 3.0, "{theme}");
+        }
+
+        // Gruvbox Dark's selection color as accent is nearly invisible on its bg.
+        let gruvbox = ThemeName::GruvboxDark.palette();
+        let dim = gruvbox.with_accent(gruvbox.selection);
+        assert!(color::contrast_ratio(dim.accent, dim.bg) < 3.0);
+        let ring = dim.focus_ring_color();
+        assert_ne!(ring, dim.accent);
+        assert!(color::contrast_ratio(ring, dim.bg) >= 3.0);
+
+        // With nothing better available, the highest-contrast candidate wins.
+        let gray = Color::Rgb(60, 60, 60);
+        let flat = ThemePalette {
+            accent: gray,
+            secondary: gray,
+            info: gray,
+            fg: Color::Rgb(90, 90, 90),
+            bg: Color::Rgb(40, 40, 40),
+            ..gruvbox
+        };
+        assert_eq!(flat.focus_ring_color(), flat.fg);
+        assert_eq!(dim.transparent_bg().focus_ring_color(), dim.accent);
+    }
+
+    #[test]
+    fn test_complementary_accent_rotates_hue() {
+        let hsl = |c: Color| {
+            let (red, green, blue) = color::to_rgb(c).expect("built-in colors are RGB");
+            color::rgb_to_hsl(red, green, blue)
+        };
+        for &theme in ThemeName::all() {
+            let palette = theme.palette();
+            let (hue, sat, light) = hsl(palette.accent);
+            let (c_hue, c_sat, c_light) = hsl(palette.complementary_accent());
+            let diff = (c_hue - hue).rem_euclid(360.0);
+            assert!((diff - 180.0).abs() < 2.0, "{theme}: {hue} -> {c_hue}");
+            assert!(
+                (c_sat - sat).abs() < 0.02 && (c_light - light).abs() < 0.01,
+                "{theme}"
+            );
+        }
+
+        let reset = ThemePalette::default().with_accent(Color::Reset);
+        assert_eq!(reset.complementary_accent(), reset.secondary);
+    }
+
+    #[test]
+    fn test_diff_lists_changed_fields() {
+        let dracula = ThemeName::Dracula.palette();
+        let nord = ThemeName::Nord.palette();
+        assert!(dracula.diff(&dracula).is_empty());
+
+        let diff = dracula.diff(&nord);
+        assert_eq!(diff.len(), 10);
+        for ((name, ours, theirs), ((field, a), (_, b))) in diff
+            .into_iter()
+            .zip(dracula.iter_fields().zip(nord.iter_fields()))
+        {
+            assert_eq!((name, ours, theirs), (field, a, b));
+        }
+
+        let tweaked = dracula.with_accent(nord.accent);
+        assert_eq!(
+            nord.diff(&tweaked).iter().map(|d| d.0).collect::<Vec<_>>(),
+            [
+                "secondary",
+                "bg",
+                "fg",
+                "muted",
+                "selection",
+                "error",
+                "warning",
+                "success",
+                "info"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_iter_fields_matches_colors() {
+        let palette = ThemeName::RosePine.palette();
+        assert!(palette.iter_fields().eq(palette.colors()));
+        assert!(palette
+            .iter_fields()
+            .rev()
+            .eq(palette.colors().into_iter().rev()));
+    }
+
+    #[test]
+    fn test_iter_labels_every_field() {
+        let palette = ThemeName::GruvboxDark.palette();
+        assert_eq!(palette.iter().len(), 10);
+        assert!(palette.iter().eq(palette.iter_fields()));
+        assert_eq!(
+            palette.iter().find(|(name, _)| *name == "error"),
+            Some(("error", palette.error))
+        );
+    }
+
+    #[test]
+    fn test_reduce_blue_warms_every_color() {
+        let palette = ThemeName::TokyoNight.palette();
+        let half = palette.reduce_blue(0.5);
+        for ((_, before), (_, after)) in palette.iter_fields().zip(half.iter_fields()) {
+            let (r0, g0, b0) = ThemePalette::resolve_rgb(before);
+            let (r1, g1, b1) = ThemePalette::resolve_rgb(after);
+            assert!(r1 >= r0 && b1 <= b0 && g1 == g0);
+        }
+
+        let white = ThemePalette::default().with_accent(Color::White);
+        assert_eq!(white.reduce_blue(1.0).accent, Color::Rgb(255, 255, 102));
+        assert_eq!(white.reduce_blue(7.0), white.reduce_blue(1.0));
+        assert_eq!(white.reduce_blue(-1.0), white);
+        assert_eq!(white.transparent_bg().reduce_blue(1.0).bg, Color::Reset);
+    }
+
+    #[test]
+    fn test_quantize_to_user_palette() {
+        let palette = ThemePalette {
+            bg: Color::Reset,
+            ..ThemeName::GruvboxDark.palette()
+        };
+        let user = [
+            Color::Reset,
+            Color::Indexed(0),
+            Color::Rgb(250, 240, 220),
+            Color::Red,
+            Color::Rgb(250, 240, 220),
+        ];
+        let snapped = palette.quantize_to(&user);
+
+        assert_eq!(snapped.bg, Color::Reset);
+        for (field, color) in snapped.iter_fields().filter(|(f, _)| *f != "bg") {
+            assert!(user[1..4].contains(&color), "{field}: {color:?}");
+        }
+        assert_eq!(snapped.fg, Color::Rgb(250, 240, 220));
+        assert_eq!(snapped.selection, Color::Indexed(0));
+        assert_eq!(snapped.error, Color::Red);
+
+        assert_eq!(palette.quantize_to(&[]), palette);
+        assert_eq!(palette.quantize_to(&[Color::Reset]), palette);
+    }
+
+    #[test]
+    fn test_average_skips_non_rgb_colors() {
+        let nord = ThemeName::Nord.palette();
+        let terminal = ThemePalette {
+            bg: Color::Reset,
+            fg: Color::White,
+            ..nord
+        };
+
+        let averaged = ThemePalette::average(&[nord, terminal]);
+        assert_eq!(averaged.bg, nord.bg);
+        assert_eq!(averaged.accent, nord.accent);
+        // Nord's fg is (236, 239, 244); White resolves to (255, 255, 255).
+        assert_eq!(averaged.fg, Color::Rgb(246, 247, 250));
+
+        let all_reset = ThemePalette::average(&[terminal, terminal]);
+        assert_eq!(all_reset.bg, Color::Reset);
+        assert_eq!(ThemePalette::average(&[nord]), nord);
+    }
+
+    #[test]
+    fn test_readable_on_picks_contrasting_text() {
+        let white = Color::Rgb(255, 255, 255);
+        let black = Color::Rgb(0, 0, 0);
+        for theme in ThemeName::all() {
+            let palette = theme.palette();
+            let on_white = palette.readable_on(white);
+            let on_black = palette.readable_on(black);
+            assert!(
+                color::luminance(on_white) < color::luminance(on_black),
+                "{theme}"
+            );
+            assert!(color::contrast_ratio(on_white, white) >= 4.5, "{theme}");
+            assert!(color::contrast_ratio(on_black, black) >= 4.5, "{theme}");
+        }
+        // Readable text keeps the palette's own foreground.
+        let dracula = ThemeName::Dracula.palette();
+        assert_eq!(dracula.readable_on(dracula.selection), dracula.fg);
+    }
+
+    #[test]
+    fn test_contrast_ratio_fg_bg() {
+        assert!(ThemeName::Dracula.palette().contrast_ratio_fg_bg() > 4.5);
+
+        // Upstream Solarized body text (base00 on base3) is just under AA.
+        let solarized = ThemeName::SolarizedLight.palette();
+        let ratio = solarized.contrast_ratio_fg_bg();
+        assert!((4.1..4.5).contains(&ratio), "{ratio}");
+        assert_eq!(solarized.contrast_grade(), ContrastGrade::AaLarge);
+
+        let dracula = ThemeName::Dracula.palette();
+        assert!(
+            (dracula.contrast_ratio(dracula.fg, dracula.bg) - dracula.contrast_ratio_fg_bg()).abs()
+                < f64::EPSILON
+        );
+
+        let named = solarized.contrast_ratio(Color::Blue, Color::Yellow);
+        let rgb = solarized.contrast_ratio(Color::Rgb(0, 0, 238), Color::Rgb(205, 205, 0));
+        assert!((named - rgb).abs() < f64::EPSILON);
+        assert!((solarized.contrast_ratio(Color::Red, Color::Reset) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_hex_round_trip_and_map() {
+        let color = ThemePalette::from_hex("#bd93f9").unwrap();
+        assert_eq!(color, Color::Rgb(189, 147, 249));
+        assert_eq!(ThemePalette::to_hex(color).as_deref(), Some("#bd93f9"));
+        assert_eq!(ThemePalette::to_hex(Color::White), None);
+        assert_eq!(ThemePalette::to_hex(Color::Indexed(196)), None);
+        assert_eq!(ThemePalette::to_hex(Color::Reset), None);
+
+        for &theme in ThemeName::all() {
+            assert_eq!(theme.palette().to_hex_map().len(), 10, "{theme}");
+        }
+
+        let palette = ThemePalette {
+            bg: Color::Reset,
+            fg: Color::White,
+            muted: Color::Indexed(244),
+            ..ThemeName::Dracula.palette()
+        };
+        let map = palette.to_hex_map();
+        assert_eq!(map.len(), 7);
+        for field in ["bg", "fg", "muted"] {
+            assert!(!map.contains_key(field), "{field}");
+        }
+        for (field, hex) in &map {
+            let (_, color) = palette.iter_fields().find(|(f, _)| f == field).unwrap();
+            assert_eq!(ThemePalette::from_hex(hex), Ok(color));
+        }
+    }
+
+    #[test]
+    fn test_brightness_orders_backgrounds() {
+        let latte = ThemeName::CatppuccinLatte.palette();
+        let dracula = ThemeName::Dracula.palette();
+        assert!(latte.brightness() > dracula.brightness());
+        assert!(latte.luminance() > dracula.luminance());
+
+        for &theme in ThemeName::all() {
+            let palette = theme.palette();
+            assert!(palette.brightness() <= 255);
+            assert_eq!(palette.is_light(), palette.brightness() > 127, "{theme}");
+        }
+
+        let named = ThemePalette {
+            bg: Color::White,
+            ..dracula
+        };
+        assert_eq!(named.brightness(), 255);
+        assert!(named.is_light());
+        assert_eq!(dracula.transparent_bg().brightness(), 0);
+    }
+
+    #[test]
+    fn test_gradient_includes_endpoints() {
+        let palette = ThemeName::Nord.palette();
+        let (a, b) = (Color::Rgb(0, 100, 200), Color::Rgb(200, 100, 0));
+        assert_eq!(palette.gradient(a, b, 2), [a, b]);
+        assert_eq!(palette.gradient(a, a, 5), [a; 5]);
+        assert_eq!(palette.gradient(a, b, 3), [a, Color::Rgb(100, 100, 100), b]);
+        assert_eq!(palette.gradient(a, b, 1), [a]);
+        assert!(palette.gradient(a, b, 0).is_empty());
+
+        assert_eq!(
+            palette.gradient(a, Color::Reset, 3),
+            [a, Color::Reset, Color::Reset]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_hex_matches_from_hex() {
+        use crate::hex::HexColor;
+
+        for input in [
+            "#bd93f9",
+            "bd93f9",
+            "#BD93F9",
+            "#f0a",
+            "#bd93f980",
+            "#bd93f9zz",
+            "#bd93f",
+            "#ffff",
+            "##fff",
+            "#bd93fé",
+            "",
+        ] {
+            let json = serde_json::to_string(input).unwrap();
+            let serde = serde_json::from_str::<HexColor>(&json).map(|c| c.0).ok();
+            assert_eq!(serde, ThemePalette::from_hex(input).ok(), "{input:?}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_palette_serde_round_trips_as_hex() {
+        let palette = ThemeName::Dracula.palette();
+        let json = serde_json::to_value(palette).unwrap();
+        assert_eq!(json["accent"], "#bd93f9");
+        assert_eq!(json["bg"], "#282a36");
+        assert_eq!(
+            serde_json::from_value::<ThemePalette>(json).unwrap(),
+            palette
+        );
+
+        for &theme in ThemeName::all() {
+            let json = serde_json::to_string(&theme.palette()).unwrap();
+            assert_eq!(
+                serde_json::from_str::<ThemePalette>(&json).unwrap(),
+                theme.palette()
+            );
+        }
+
+        let transparent = palette.transparent_bg();
+        let json = serde_json::to_value(transparent).unwrap();
+        assert_eq!(json["bg"], "reset");
+        assert_eq!(
+            serde_json::from_value::<ThemePalette>(json).unwrap(),
+            transparent
+        );
+    }
+
+    #[test]
+    fn test_on_color_prefers_palette_colors() {
+        let palette = ThemeName::CatppuccinLatte.palette();
+        assert_eq!(palette.on_selection(), palette.fg);
     }
 }
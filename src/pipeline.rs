@@ -0,0 +1,223 @@
+//! Composable palette transforms.
+//!
+//! This module contains the [`PaletteAdapter`] trait, a few built-in
+//! adapters wrapping the palette's transform methods, and
+//! [`PalettePipeline`], which applies a list of adapters in order.
+
+use crate::palette::ThemePalette;
+use alloc::{boxed::Box, vec::Vec};
+
+/// A transform from one palette to another.
+///
+/// Implemented by the built-in adapters ([`SoftenExtremes`], [`Tint`],
+/// [`EInk`]) and by any `Fn(ThemePalette) -> ThemePalette`, so plain
+/// functions such as [`ThemePalette::transparent_bg`] and closures can be
+/// chained in a [`PalettePipeline`] too.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_themes::{PaletteAdapter, ThemeName, ThemePalette};
+///
+/// /// Swap the accent colors.
+/// struct SwapAccents;
+///
+/// impl PaletteAdapter for SwapAccents {
+///     fn adapt(&self, palette: ThemePalette) -> ThemePalette {
+///         ThemePalette {
+///             accent: palette.secondary,
+///             secondary: palette.accent,
+///             ..palette
+///         }
+///     }
+/// }
+///
+/// let palette = ThemeName::Dracula.palette();
+/// assert_eq!(SwapAccents.adapt(palette).accent, palette.secondary);
+/// ```
+pub trait PaletteAdapter {
+    /// Returns the transformed palette.
+    fn adapt(&self, palette: ThemePalette) -> ThemePalette;
+}
+
+impl<F: Fn(ThemePalette) -> ThemePalette> PaletteAdapter for F {
+    fn adapt(&self, palette: ThemePalette) -> ThemePalette {
+        self(palette)
+    }
+}
+
+/// Adapter for [`ThemePalette::soften_extremes`].
+///
+/// The [`Default`] uses [`ThemePalette::SOFTEN_FLOOR`] and
+/// [`ThemePalette::SOFTEN_CEIL`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SoftenExtremes {
+    /// Channels of near-black colors are lifted to this value.
+    pub floor: u8,
+    /// Channels of near-white colors are capped at this value.
+    pub ceil: u8,
+}
+
+impl Default for SoftenExtremes {
+    fn default() -> Self {
+        Self {
+            floor: ThemePalette::SOFTEN_FLOOR,
+            ceil: ThemePalette::SOFTEN_CEIL,
+        }
+    }
+}
+
+impl PaletteAdapter for SoftenExtremes {
+    fn adapt(&self, palette: ThemePalette) -> ThemePalette {
+        palette.soften_extremes(self.floor, self.ceil)
+    }
+}
+
+/// Adapter for [`ThemePalette::tint`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Tint {
+    /// Offset added to the red channel.
+    pub dr: i16,
+    /// Offset added to the green channel.
+    pub dg: i16,
+    /// Offset added to the blue channel.
+    pub db: i16,
+}
+
+impl PaletteAdapter for Tint {
+    fn adapt(&self, palette: ThemePalette) -> ThemePalette {
+        palette.tint(self.dr, self.dg, self.db)
+    }
+}
+
+/// Adapter for [`ThemePalette::for_eink`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EInk;
+
+impl PaletteAdapter for EInk {
+    fn adapt(&self, palette: ThemePalette) -> ThemePalette {
+        palette.for_eink()
+    }
+}
+
+/// An ordered chain of [`PaletteAdapter`]s applied as one transform.
+///
+/// Build the pipeline once (e.g. from user settings) with
+/// [`then`](Self::then), then run every palette through
+/// [`apply`](Self::apply). Adapters run in the order they were added, each
+/// receiving the previous one's output; an empty pipeline returns the palette
+/// unchanged. A pipeline is itself a [`PaletteAdapter`], so pipelines nest.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_themes::{PalettePipeline, SoftenExtremes, ThemeName, ThemePalette, Tint};
+///
+/// let pipeline = PalettePipeline::new()
+///     .then(Tint { dr: 8, dg: 0, db: -8 })
+///     .then(SoftenExtremes::default())
+///     .then(ThemePalette::transparent_bg);
+///
+/// let palette = ThemeName::Nord.palette();
+/// let adapted = pipeline.apply(palette);
+/// assert_eq!(adapted, palette.tint(8, 0, -8).soften_extremes(10, 245).transparent_bg());
+/// ```
+#[derive(Default)]
+pub struct PalettePipeline {
+    steps: Vec<Box<dyn PaletteAdapter>>,
+}
+
+impl PalettePipeline {
+    /// Create an empty pipeline.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append `adapter` to the end of the pipeline.
+    #[must_use]
+    pub fn then(mut self, adapter: impl PaletteAdapter + 'static) -> Self {
+        self.steps.push(Box::new(adapter));
+        self
+    }
+
+    /// Run `palette` through every adapter, in the order they were added.
+    #[must_use]
+    pub fn apply(&self, palette: ThemePalette) -> ThemePalette {
+        self.steps
+            .iter()
+            .fold(palette, |palette, step| step.adapt(palette))
+    }
+
+    /// Returns the number of adapters in the pipeline.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns `true` if the pipeline has no adapters.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl PaletteAdapter for PalettePipeline {
+    fn adapt(&self, palette: ThemePalette) -> ThemePalette {
+        self.apply(palette)
+    }
+}
+
+impl core::fmt::Debug for PalettePipeline {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PalettePipeline")
+            .field("steps", &self.steps.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ThemeName;
+    use alloc::format;
+    use ratatui::style::Color;
+
+    #[test]
+    fn test_pipeline_applies_steps_in_order() {
+        let palette = ThemePalette {
+            bg: Color::Rgb(0, 0, 0),
+            ..ThemeName::Nord.palette()
+        };
+        let tint = Tint {
+            dr: 0,
+            dg: 0,
+            db: 5,
+        };
+        let soften = SoftenExtremes::default();
+
+        let tint_first = PalettePipeline::new().then(tint).then(soften);
+        let soften_first = PalettePipeline::new().then(soften).then(tint);
+        assert_eq!(tint_first.apply(palette).bg, Color::Rgb(10, 10, 10));
+        assert_eq!(soften_first.apply(palette).bg, Color::Rgb(10, 10, 15));
+        assert_eq!(tint_first.len(), 2);
+
+        let empty = PalettePipeline::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.apply(palette), palette);
+    }
+
+    #[test]
+    fn test_closures_and_nested_pipelines_are_adapters() {
+        let palette = ThemeName::SolarizedLight.palette();
+        let inner = PalettePipeline::new().then(EInk);
+        let outer = PalettePipeline::new()
+            .then(inner)
+            .then(|p: ThemePalette| p.with_accent(Color::Rgb(1, 2, 3)));
+
+        let adapted = outer.apply(palette);
+        assert_eq!(adapted.bg, palette.for_eink().bg);
+        assert_eq!(adapted.accent, Color::Rgb(1, 2, 3));
+        assert_eq!(format!("{outer:?}"), "PalettePipeline { steps: 2 }");
+    }
+}
@@ -0,0 +1,174 @@
+//! Choosing a theme from several configuration sources.
+//!
+//! This module contains [`ThemeResolver`], which applies the usual
+//! "CLI flag > environment variable > config file > default" precedence, and
+//! [`ThemeSource`], which reports where the chosen theme came from.
+
+use crate::theme::ThemeName;
+
+/// Where a theme resolved by [`ThemeResolver`] came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThemeSource {
+    /// A command-line argument.
+    Cli,
+    /// An environment variable.
+    Env,
+    /// The application's config file.
+    Config,
+    /// The fallback default.
+    Default,
+}
+
+/// Resolves the active theme from a CLI flag, an environment variable, a
+/// config value and a default, in that order of precedence.
+///
+/// Every source is optional. A source that is missing or fails to parse is
+/// skipped (with a warning when the `log` feature is enabled), so a typo in
+/// one place never prevents the app from starting. Without an explicit
+/// [`default`](Self::default), [`ThemeName::default()`] is used.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_themes::{ThemeName, ThemeResolver, ThemeSource};
+///
+/// let cli_flag: Option<&str> = None;
+/// let from_config = Some(ThemeName::Nord);
+///
+/// let (theme, source) = ThemeResolver::new()
+///     .cli(cli_flag)
+///     .env_var("MY_APP_THEME")
+///     .config(from_config)
+///     .default(ThemeName::Dracula)
+///     .resolve();
+///
+/// assert_eq!(theme, ThemeName::Nord);
+/// assert_eq!(source, ThemeSource::Config);
+///
+/// // A bad CLI value is skipped rather than aborting.
+/// let (theme, source) = ThemeResolver::new().cli(Some("drakula")).resolve();
+/// assert_eq!((theme, source), (ThemeName::default(), ThemeSource::Default));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThemeResolver<'a> {
+    cli: Option<&'a str>,
+    env_var: Option<&'a str>,
+    config: Option<ThemeName>,
+    default: ThemeName,
+}
+
+impl<'a> ThemeResolver<'a> {
+    /// Create a resolver with no sources and [`ThemeName::default()`] as fallback.
+    #[must_use]
+    pub fn new() -> Self {
+        <Self as Default>::default()
+    }
+
+    /// Set the theme name passed on the command line, if any.
+    #[must_use]
+    pub const fn cli(mut self, value: Option<&'a str>) -> Self {
+        self.cli = value;
+        self
+    }
+
+    /// Set the environment variable to read the theme name from.
+    ///
+    /// The variable is read when [`resolve`](Self::resolve) is called.
+    #[must_use]
+    pub const fn env_var(mut self, name: &'a str) -> Self {
+        self.env_var = Some(name);
+        self
+    }
+
+    /// Set the theme loaded from the config file, if any.
+    #[must_use]
+    pub const fn config(mut self, value: Option<ThemeName>) -> Self {
+        self.config = value;
+        self
+    }
+
+    /// Set the theme used when no other source provides one.
+    #[must_use]
+    pub const fn default(mut self, theme: ThemeName) -> Self {
+        self.default = theme;
+        self
+    }
+
+    /// Pick the theme from the highest-precedence source that provides a valid one.
+    ///
+    /// Returns the theme together with the [`ThemeSource`] it came from.
+    #[must_use]
+    pub fn resolve(&self) -> (ThemeName, ThemeSource) {
+        if let Some(theme) = self
+            .cli
+            .and_then(|value| parse_layer(value, "command line"))
+        {
+            return (theme, ThemeSource::Cli);
+        }
+
+        let env = self.env_var.and_then(|name| std::env::var(name).ok());
+        if let Some(theme) = env
+            .as_deref()
+            .and_then(|value| parse_layer(value, "environment"))
+        {
+            return (theme, ThemeSource::Env);
+        }
+
+        self.config
+            .map_or((self.default, ThemeSource::Default), |theme| {
+                (theme, ThemeSource::Config)
+            })
+    }
+}
+
+/// Parse one source's value, skipping it if it is not a known theme.
+///
+/// Uses [`ThemeName::parse`] rather than `FromStr`, which logs on its own,
+/// so each skipped value is reported once.
+#[cfg_attr(not(feature = "log"), allow(unused_variables))]
+fn parse_layer(value: &str, layer: &str) -> Option<ThemeName> {
+    let parsed = ThemeName::parse(value).ok();
+    if parsed.is_none() {
+        log_fallback!("ignoring unknown theme {value:?} from {layer}");
+    }
+    parsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precedence() {
+        let resolver = ThemeResolver::new()
+            .config(Some(ThemeName::Nord))
+            .default(ThemeName::Kanagawa);
+        assert_eq!(resolver.resolve(), (ThemeName::Nord, ThemeSource::Config));
+        assert_eq!(
+            resolver.cli(Some("tokyo-night")).resolve(),
+            (ThemeName::TokyoNight, ThemeSource::Cli)
+        );
+        assert_eq!(
+            resolver.config(None).resolve(),
+            (ThemeName::Kanagawa, ThemeSource::Default)
+        );
+    }
+
+    #[test]
+    fn test_env_layer_and_invalid_values_are_skipped() {
+        // The variable name is unique to this test, so parallel tests don't race.
+        std::env::set_var("RATATUI_THEMES_TEST_RESOLVER", "gruvbox-light");
+        let resolver = ThemeResolver::new()
+            .cli(Some("not-a-theme"))
+            .env_var("RATATUI_THEMES_TEST_RESOLVER")
+            .config(Some(ThemeName::Nord));
+        assert_eq!(
+            resolver.resolve(),
+            (ThemeName::GruvboxLight, ThemeSource::Env)
+        );
+
+        std::env::set_var("RATATUI_THEMES_TEST_RESOLVER", "???");
+        assert_eq!(resolver.resolve(), (ThemeName::Nord, ThemeSource::Config));
+        std::env::remove_var("RATATUI_THEMES_TEST_RESOLVER");
+    }
+}
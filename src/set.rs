@@ -0,0 +1,186 @@
+//! Compact sets of themes.
+//!
+//! This module contains [`ThemeSet`], a copyable bitset of [`ThemeName`]s for
+//! things like an "enabled themes" list loaded from configuration.
+
+use crate::theme::ThemeName;
+use alloc::{string::String, string::ToString, vec::Vec};
+
+/// A set of themes stored as a bitset keyed by [`ThemeName::stable_id`].
+///
+/// The set is `Copy`, allocation-free, and always iterates in
+/// [`ThemeName::all()`] order regardless of insertion order.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_themes::{ThemeName, ThemeSet};
+///
+/// let (enabled, unknown) = ThemeSet::from_slugs(&["dracula", "nord", "drakula"]);
+///
+/// assert!(enabled.contains(ThemeName::Nord));
+/// assert_eq!(enabled.len(), 2);
+/// assert_eq!(unknown, ["drakula"]);
+///
+/// let slugs: Vec<&str> = enabled.slugs().collect();
+/// assert_eq!(slugs, ["dracula", "nord"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ThemeSet {
+    bits: u64,
+}
+
+impl ThemeSet {
+    /// Create an empty set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Create a set containing every built-in theme.
+    #[must_use]
+    pub fn all() -> Self {
+        ThemeName::all().iter().copied().collect()
+    }
+
+    /// Bit mask for a theme.
+    ///
+    /// Every stable id must fit in the `u64`, which is checked below at
+    /// compile time.
+    const fn bit(theme: ThemeName) -> u64 {
+        1 << theme.stable_id()
+    }
+
+    /// Add a theme to the set. Returns `true` if it was not already present.
+    pub fn insert(&mut self, theme: ThemeName) -> bool {
+        let added = !self.contains(theme);
+        self.bits |= Self::bit(theme);
+        added
+    }
+
+    /// Remove a theme from the set. Returns `true` if it was present.
+    pub fn remove(&mut self, theme: ThemeName) -> bool {
+        let present = self.contains(theme);
+        self.bits &= !Self::bit(theme);
+        present
+    }
+
+    /// Check whether the set contains `theme`.
+    #[must_use]
+    pub const fn contains(&self, theme: ThemeName) -> bool {
+        self.bits & Self::bit(theme) != 0
+    }
+
+    /// Returns the number of themes in the set.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Check whether the set is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Iterate over the themes in the set, in [`ThemeName::all()`] order.
+    pub fn iter(&self) -> impl Iterator<Item = ThemeName> + '_ {
+        ThemeName::all()
+            .iter()
+            .copied()
+            .filter(|&theme| self.contains(theme))
+    }
+
+    /// Iterate over the slugs of the themes in the set, for serialization.
+    pub fn slugs(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.iter().map(ThemeName::slug)
+    }
+
+    /// Build a set from user-supplied theme names.
+    ///
+    /// Each entry is parsed with [`ThemeName::parse`], so display names and
+    /// aliases are accepted as well as slugs. Returns the
+    /// set of recognized themes together with every entry that could not be
+    /// parsed, in input order, so callers can report them.
+    #[must_use]
+    pub fn from_slugs(slugs: &[&str]) -> (Self, Vec<String>) {
+        let mut set = Self::new();
+        let mut unknown = Vec::new();
+        for &slug in slugs {
+            match ThemeName::parse(slug) {
+                Ok(theme) => {
+                    set.insert(theme);
+                }
+                Err(_) => unknown.push(slug.to_string()),
+            }
+        }
+        (set, unknown)
+    }
+}
+
+// Fail the build, rather than overflow `ThemeSet::bit`, once a theme is
+// assigned a stable id past the capacity of the bitset.
+const _: () = {
+    let all = ThemeName::all();
+    let mut i = 0;
+    while i < all.len() {
+        assert!(
+            all[i].stable_id() < 64,
+            "ThemeSet stores stable ids in a u64; widen it before adding more themes"
+        );
+        i += 1;
+    }
+};
+
+impl FromIterator<ThemeName> for ThemeSet {
+    fn from_iter<I: IntoIterator<Item = ThemeName>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<ThemeName> for ThemeSet {
+    fn extend<I: IntoIterator<Item = ThemeName>>(&mut self, iter: I) {
+        for theme in iter {
+            self.insert(theme);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_and_iterate_in_order() {
+        let set: ThemeSet = [ThemeName::Cyberpunk, ThemeName::Dracula, ThemeName::Dracula]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [ThemeName::Dracula, ThemeName::Cyberpunk]
+        );
+    }
+
+    #[test]
+    fn test_all_round_trips_through_slugs() {
+        let set = ThemeSet::all();
+        assert_eq!(set.len(), ThemeName::all().len());
+
+        let slugs: Vec<&str> = set.slugs().collect();
+        let (parsed, unknown) = ThemeSet::from_slugs(&slugs);
+        assert_eq!(parsed, set);
+        assert!(unknown.is_empty());
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut set = ThemeSet::new();
+        assert!(set.insert(ThemeName::Nord));
+        assert!(!set.insert(ThemeName::Nord));
+        assert!(set.remove(ThemeName::Nord));
+        assert!(set.is_empty());
+    }
+}
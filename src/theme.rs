@@ -3,8 +3,17 @@
 //! This module contains the [`ThemeName`] enum representing all available themes,
 //! and the [`Theme`] struct which provides a convenient wrapper for working with themes.
 
+use crate::color::{self, ContrastGrade};
+use crate::macros::theme_table;
 use crate::palette::ThemePalette;
-use ratatui::style::Color;
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use ratatui::style::{Color, Modifier, Style};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -32,6 +41,10 @@ use serde::{Deserialize, Serialize};
 /// let theme = ThemeName::TokyoNight;
 /// ```
 ///
+/// Deserialization accepts every spelling [`FromStr`](core::str::FromStr)
+/// does, so hand-edited configs may also say `"Tokyo Night"` or
+/// `"tokyonight"`.
+///
 /// # Example
 ///
 /// ```rust
@@ -47,7 +60,7 @@ use serde::{Deserialize, Serialize};
 /// }
 /// ```
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 #[non_exhaustive]
 pub enum ThemeName {
@@ -172,6 +185,9 @@ pub enum ThemeName {
 }
 
 impl ThemeName {
+    /// The number of built-in themes, i.e. `ThemeName::all().len()`.
+    pub const COUNT: usize = 15;
+
     /// Returns a slice containing all available theme names.
     ///
     /// Useful for building theme selection UIs or iterating over all themes.
@@ -210,6 +226,206 @@ impl ThemeName {
         ]
     }
 
+    /// Returns every theme's [`display_name()`](Self::display_name), in [`all()`](Self::all) order.
+    ///
+    /// Handy for dropdowns and completion lists without mapping `all()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// const NAMES: [&str; ThemeName::COUNT] = ThemeName::display_names();
+    /// assert_eq!(NAMES[7], "Tokyo Night");
+    /// ```
+    #[must_use]
+    pub const fn display_names() -> [&'static str; Self::COUNT] {
+        let all = Self::all();
+        let mut names = [""; Self::COUNT];
+        let mut i = 0;
+        while i < Self::COUNT {
+            names[i] = all[i].display_name();
+            i += 1;
+        }
+        names
+    }
+
+    /// Returns every theme's [`slug()`](Self::slug), in [`all()`](Self::all) order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let slugs = ThemeName::slugs();
+    /// assert_eq!(slugs[0], "dracula");
+    /// assert!(slugs.contains(&"rose-pine"));
+    /// ```
+    #[must_use]
+    pub const fn slugs() -> [&'static str; Self::COUNT] {
+        let all = Self::all();
+        let mut slugs = [""; Self::COUNT];
+        let mut i = 0;
+        while i < Self::COUNT {
+            slugs[i] = all[i].slug();
+            i += 1;
+        }
+        slugs
+    }
+
+    /// Parse a theme name without allocating.
+    ///
+    /// Accepts the same spellings as [`FromStr`](core::str::FromStr): case,
+    /// spaces and punctuation are ignored, accented Latin letters match
+    /// their plain form (so `"Rosé Pine"` parses), and short aliases such as
+    /// `"mocha"` are recognized. Unlike `FromStr`, the error carries no
+    /// message or suggestion, so this works without an allocator; use
+    /// [`parse_with_suggestion()`](Self::parse_with_suggestion) for a hint.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeNameParseError::Unknown`] if `s` is not a known theme name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{ThemeName, ThemeNameParseError};
+    ///
+    /// assert_eq!(ThemeName::parse("Tokyo Night"), Ok(ThemeName::TokyoNight));
+    /// assert_eq!(ThemeName::parse("mocha"), Ok(ThemeName::CatppuccinMocha));
+    /// assert_eq!(ThemeName::parse("drakula"), Err(ThemeNameParseError::Unknown));
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, ThemeNameParseError> {
+        /// Longer than any accepted normalized name.
+        const MAX_LEN: usize = 16;
+
+        let mut normalized = [0u8; MAX_LEN];
+        let mut len = 0;
+        for c in s
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .map(fold_diacritic)
+        {
+            // Every accepted name is ASCII, so anything else cannot match.
+            if !c.is_ascii() || len == MAX_LEN {
+                return Err(ThemeNameParseError::Unknown);
+            }
+            normalized[len] = c.to_ascii_lowercase() as u8;
+            len += 1;
+        }
+        core::str::from_utf8(&normalized[..len])
+            .ok()
+            .and_then(Self::from_normalized)
+            .ok_or(ThemeNameParseError::Unknown)
+    }
+
+    /// Parse a theme name, suggesting the closest known theme on failure.
+    ///
+    /// Accepts the same spellings as [`FromStr`](core::str::FromStr). On
+    /// failure, returns the input together with the theme whose slug is
+    /// nearest by edit distance, or `None` if nothing is close enough to be a
+    /// plausible typo.
+    ///
+    /// # Errors
+    ///
+    /// Returns `(input, suggestion)` if `s` is not a known theme name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::parse_with_suggestion("nord"), Ok(ThemeName::Nord));
+    /// assert_eq!(
+    ///     ThemeName::parse_with_suggestion("drakula"),
+    ///     Err(("drakula".to_string(), Some(ThemeName::Dracula)))
+    /// );
+    /// assert_eq!(
+    ///     ThemeName::parse_with_suggestion("vim-default"),
+    ///     Err(("vim-default".to_string(), None))
+    /// );
+    /// ```
+    pub fn parse_with_suggestion(s: &str) -> Result<Self, (String, Option<Self>)> {
+        Self::parse(s).map_err(|_| (s.to_string(), suggest(&normalize_name(s))))
+    }
+
+    /// Parse a comma-separated list of theme names, such as `"dracula, nord"`.
+    ///
+    /// Each entry is trimmed and parsed with [`parse()`](Self::parse). Blank
+    /// and unknown entries are skipped rather than failing the whole list
+    /// (unknown ones are logged with the `log` feature, once each), and
+    /// repeated themes are kept only once, in order of first appearance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(
+    ///     ThemeName::parse_list("dracula, ,nord,drakula,Dracula"),
+    ///     vec![ThemeName::Dracula, ThemeName::Nord]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn parse_list(list: &str) -> Vec<Self> {
+        let mut themes = Vec::new();
+        for entry in list.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let Ok(theme) = Self::parse(entry) else {
+                log_fallback!("skipping unknown theme {entry:?} in list {list:?}");
+                continue;
+            };
+            if !themes.contains(&theme) {
+                themes.push(theme);
+            }
+        }
+        themes
+    }
+
+    /// Read a comma-separated list of theme names from an environment variable.
+    ///
+    /// Parses the value of `var` with [`parse_list()`](Self::parse_list), so
+    /// blank and invalid entries are skipped. A missing (or non-UTF-8)
+    /// variable yields an empty list; treat that as "no restriction".
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// // APP_THEMES=dracula,nord
+    /// let enabled = ThemeName::parse_env("APP_THEMES");
+    /// let choices: &[ThemeName] = if enabled.is_empty() { ThemeName::all() } else { &enabled };
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn parse_env(var: &str) -> Vec<Self> {
+        std::env::var(var)
+            .map(|list| Self::parse_list(&list))
+            .unwrap_or_default()
+    }
+
+    /// Match an already-normalized name (lowercase, alphanumerics only).
+    fn from_normalized(normalized: &str) -> Option<Self> {
+        match normalized {
+            "dracula" => Some(Self::Dracula),
+            "onedarkpro" | "onedark" => Some(Self::OneDarkPro),
+            "nord" => Some(Self::Nord),
+            "catppuccinmocha" | "mocha" => Some(Self::CatppuccinMocha),
+            "catppuccinlatte" | "latte" => Some(Self::CatppuccinLatte),
+            "gruvboxdark" | "gruvbox" => Some(Self::GruvboxDark),
+            "gruvboxlight" => Some(Self::GruvboxLight),
+            "tokyonight" | "tokyo" => Some(Self::TokyoNight),
+            "solarizeddark" | "solarized" => Some(Self::SolarizedDark),
+            "solarizedlight" => Some(Self::SolarizedLight),
+            "monokaipro" | "monokai" => Some(Self::MonokaiPro),
+            "rosepine" | "rose" => Some(Self::RosePine),
+            "kanagawa" => Some(Self::Kanagawa),
+            "everforest" => Some(Self::Everforest),
+            "cyberpunk" => Some(Self::Cyberpunk),
+            _ => None,
+        }
+    }
+
     /// Returns the human-readable display name for the theme.
     ///
     /// This is useful for UI display where you want properly formatted
@@ -244,6 +460,28 @@ impl ThemeName {
         }
     }
 
+    /// Returns the display name, ellipsized to fit `max` terminal columns.
+    ///
+    /// Names that fit are returned whole (and borrowed). Longer names are cut
+    /// at a character boundary and end in `…`, with the result at most `max`
+    /// columns wide. Widths are Unicode display widths, so an accented `é`
+    /// counts as one column, and trailing spaces before the ellipsis are
+    /// dropped. A `max` of 0 yields an empty string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::Nord.display_name_truncated(12), "Nord");
+    /// assert_eq!(ThemeName::CatppuccinMocha.display_name_truncated(13), "Catppuccin M…");
+    /// assert_eq!(ThemeName::RosePine.display_name_truncated(5), "Rosé…");
+    /// ```
+    #[must_use]
+    pub fn display_name_truncated(self, max: usize) -> Cow<'static, str> {
+        truncate_to_width(self.display_name(), max)
+    }
+
     /// Returns the kebab-case slug for the theme (used in config files).
     ///
     /// This matches the serde serialization format and is suitable for
@@ -279,151 +517,1091 @@ impl ThemeName {
         }
     }
 
-    /// Returns the next theme in the list, wrapping around at the end.
+    /// Look up a theme by its exact [`slug()`](Self::slug).
     ///
-    /// Useful for implementing theme cycling with a "next theme" button.
+    /// Unlike [`FromStr`](core::str::FromStr), no normalization or aliases are
+    /// applied: only the canonical kebab-case slug matches, so
+    /// `from_slug(theme.slug())` always round-trips. Being `const`, it also
+    /// works in constant contexts.
     ///
     /// # Example
     ///
     /// ```rust
     /// use ratatui_themes::ThemeName;
     ///
-    /// let theme = ThemeName::Dracula;
-    /// assert_eq!(theme.next(), ThemeName::OneDarkPro);
+    /// const THEME: Option<ThemeName> = ThemeName::from_slug("rose-pine");
+    /// assert_eq!(THEME, Some(ThemeName::RosePine));
     ///
-    /// // Wraps around at the end
-    /// let last = ThemeName::Cyberpunk;
-    /// assert_eq!(last.next(), ThemeName::Dracula);
+    /// assert_eq!(ThemeName::from_slug("Rose Pine"), None);
+    /// assert_eq!(ThemeName::from_slug("rose"), None);
     /// ```
     #[must_use]
-    pub fn next(self) -> Self {
-        let themes = Self::all();
-        let current = themes.iter().position(|&t| t == self).unwrap_or(0);
-        themes[(current + 1) % themes.len()]
+    pub const fn from_slug(slug: &str) -> Option<Self> {
+        let all = Self::all();
+        let mut i = 0;
+        while i < all.len() {
+            if bytes_eq(all[i].slug().as_bytes(), slug.as_bytes()) {
+                return Some(all[i]);
+            }
+            i += 1;
+        }
+        None
     }
 
-    /// Returns the previous theme in the list, wrapping around at the beginning.
+    /// Returns a short, unique code for the theme, for space-constrained UIs.
     ///
-    /// Useful for implementing theme cycling with a "previous theme" button.
+    /// Codes are at most five characters and never shared between themes, so
+    /// a cramped status bar can still show which theme is active.
     ///
     /// # Example
     ///
     /// ```rust
     /// use ratatui_themes::ThemeName;
     ///
-    /// let theme = ThemeName::OneDarkPro;
-    /// assert_eq!(theme.prev(), ThemeName::Dracula);
+    /// assert_eq!(ThemeName::Dracula.abbreviation(), "DRC");
+    /// assert_eq!(ThemeName::CatppuccinMocha.abbreviation(), "CAT-M");
+    /// ```
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        const ABBREVIATIONS: [&str; ThemeName::COUNT] = theme_table! {
+            Dracula => "DRC",
+            OneDarkPro => "ODP",
+            Nord => "NRD",
+            CatppuccinMocha => "CAT-M",
+            CatppuccinLatte => "CAT-L",
+            GruvboxDark => "GRV-D",
+            GruvboxLight => "GRV-L",
+            TokyoNight => "TKN",
+            SolarizedDark => "SOL-D",
+            SolarizedLight => "SOL-L",
+            MonokaiPro => "MKP",
+            RosePine => "RSP",
+            Kanagawa => "KNG",
+            Everforest => "EVF",
+            Cyberpunk => "CYB",
+        };
+        self.lookup::<&str>(&ABBREVIATIONS)
+    }
+
+    /// Returns an emoji representing the theme, for icon-driven menus.
     ///
-    /// // Wraps around at the beginning
-    /// let first = ThemeName::Dracula;
-    /// assert_eq!(first.prev(), ThemeName::Cyberpunk);
+    /// Every theme has its own icon. Emoji render two cells wide in most
+    /// terminals, and some fonts lack them entirely, so keep a text fallback
+    /// such as [`abbreviation()`](Self::abbreviation) available.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::Nord.icon(), '❄');
+    /// let label = format!("{} {}", ThemeName::Everforest.icon(), ThemeName::Everforest);
+    /// assert_eq!(label, "🌲 Everforest");
     /// ```
     #[must_use]
-    pub fn prev(self) -> Self {
-        let themes = Self::all();
-        let current = themes.iter().position(|&t| t == self).unwrap_or(0);
-        themes[(current + themes.len() - 1) % themes.len()]
+    pub const fn icon(self) -> char {
+        const ICONS: [char; ThemeName::COUNT] = theme_table! {
+            Dracula => '🧛',
+            OneDarkPro => '⚛',
+            Nord => '❄',
+            CatppuccinMocha => '☕',
+            CatppuccinLatte => '🥛',
+            GruvboxDark => '🍂',
+            GruvboxLight => '🌾',
+            TokyoNight => '🌃',
+            SolarizedDark => '🌘',
+            SolarizedLight => '☀',
+            MonokaiPro => '🎨',
+            RosePine => '🌹',
+            Kanagawa => '🌊',
+            Everforest => '🌲',
+            Cyberpunk => '🤖',
+        };
+        *self.lookup(&ICONS)
     }
 
-    /// Returns the color palette for this theme.
+    /// Returns the border type that best fits the theme's aesthetic.
     ///
-    /// The palette contains all the semantic colors you need to style your UI.
+    /// Soft pastel themes get rounded corners, most editor ports keep plain
+    /// lines, and a few themes get something bolder. This is a curated default
+    /// used by [`Theme::block()`]; apps can always override it.
     ///
     /// # Example
     ///
     /// ```rust
+    /// use ratatui::widgets::BorderType;
     /// use ratatui_themes::ThemeName;
-    /// use ratatui::style::Style;
     ///
-    /// let palette = ThemeName::Nord.palette();
+    /// assert_eq!(ThemeName::CatppuccinMocha.recommended_border(), BorderType::Rounded);
+    /// assert_eq!(ThemeName::Cyberpunk.recommended_border(), BorderType::Thick);
+    /// ```
+    #[cfg(feature = "widgets")]
+    #[must_use]
+    pub const fn recommended_border(self) -> ratatui::widgets::BorderType {
+        use ratatui::widgets::BorderType;
+
+        const BORDERS: [BorderType; ThemeName::COUNT] = theme_table! {
+            Dracula => BorderType::Rounded,
+            OneDarkPro => BorderType::Plain,
+            Nord => BorderType::Plain,
+            CatppuccinMocha => BorderType::Rounded,
+            CatppuccinLatte => BorderType::Rounded,
+            GruvboxDark => BorderType::Plain,
+            GruvboxLight => BorderType::Plain,
+            TokyoNight => BorderType::Rounded,
+            SolarizedDark => BorderType::Plain,
+            SolarizedLight => BorderType::Plain,
+            MonokaiPro => BorderType::Plain,
+            RosePine => BorderType::Rounded,
+            Kanagawa => BorderType::Double,
+            Everforest => BorderType::Rounded,
+            Cyberpunk => BorderType::Thick,
+        };
+        *self.lookup(&BORDERS)
+    }
+
+    /// Returns the text modifier that gives accent text the theme's intended feel.
     ///
-    /// // Use palette colors in your styles
-    /// let style = Style::default()
-    ///     .fg(palette.fg)
-    ///     .bg(palette.bg);
+    /// High-contrast, saturated themes read best with bold emphasis, themes
+    /// known for italic highlights get italics, and soft, muted themes let
+    /// color alone carry emphasis (an empty modifier). This is a curated
+    /// default used by [`Theme::emphasis_style()`] and
+    /// [`ThemeStyleCache::emphasis()`](crate::ThemeStyleCache::emphasis); apps
+    /// can always override it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Modifier;
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::Cyberpunk.recommended_emphasis(), Modifier::BOLD);
+    /// assert!(ThemeName::RosePine.recommended_emphasis().is_empty());
     /// ```
     #[must_use]
-    #[allow(clippy::too_many_lines)]
-    pub const fn palette(self) -> ThemePalette {
-        match self {
-            // Dracula: https://draculatheme.com/contribute
-            Self::Dracula => ThemePalette {
-                accent: Color::Rgb(189, 147, 249),    // Purple
-                secondary: Color::Rgb(255, 121, 198), // Pink
-                bg: Color::Rgb(40, 42, 54),           // Background
-                fg: Color::Rgb(248, 248, 242),        // Foreground
-                muted: Color::Rgb(98, 114, 164),      // Comment
-                selection: Color::Rgb(68, 71, 90),    // Selection
-                error: Color::Rgb(255, 85, 85),       // Red
-                warning: Color::Rgb(255, 184, 108),   // Orange
-                success: Color::Rgb(80, 250, 123),    // Green
-                info: Color::Rgb(139, 233, 253),      // Cyan
-            },
+    pub const fn recommended_emphasis(self) -> Modifier {
+        const EMPHASIS: [Modifier; ThemeName::COUNT] = theme_table! {
+            Dracula => Modifier::BOLD,
+            OneDarkPro => Modifier::BOLD,
+            Nord => Modifier::empty(),
+            CatppuccinMocha => Modifier::BOLD,
+            CatppuccinLatte => Modifier::BOLD,
+            GruvboxDark => Modifier::BOLD,
+            GruvboxLight => Modifier::BOLD,
+            TokyoNight => Modifier::BOLD,
+            SolarizedDark => Modifier::empty(),
+            SolarizedLight => Modifier::empty(),
+            MonokaiPro => Modifier::ITALIC,
+            RosePine => Modifier::empty(),
+            Kanagawa => Modifier::ITALIC,
+            Everforest => Modifier::empty(),
+            Cyberpunk => Modifier::BOLD,
+        };
+        *self.lookup(&EMPHASIS)
+    }
 
-            // One Dark Pro: https://github.com/Binaryify/OneDark-Pro
-            Self::OneDarkPro => ThemePalette {
-                accent: Color::Rgb(97, 175, 239),     // Blue
-                secondary: Color::Rgb(198, 120, 221), // Magenta
-                bg: Color::Rgb(40, 44, 52),           // Background
-                fg: Color::Rgb(171, 178, 191),        // Foreground
-                muted: Color::Rgb(92, 99, 112),       // Comment
-                selection: Color::Rgb(62, 68, 81),    // Selection
-                error: Color::Rgb(224, 108, 117),     // Red
-                warning: Color::Rgb(229, 192, 123),   // Yellow
-                success: Color::Rgb(152, 195, 121),   // Green
-                info: Color::Rgb(86, 182, 194),       // Cyan
-            },
+    /// Returns the screen brightness the theme is most comfortable at.
+    ///
+    /// This is curated guidance, not a measurement: very dark themes such as
+    /// Cyberpunk suit a dimmed (or OLED) display, light themes need a bright
+    /// one, and the rest sit in between. Show it next to the numeric
+    /// background brightness in a theme info panel, or filter on
+    /// [`DisplayBrightness::Low`] to list OLED-friendly themes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{DisplayBrightness, ThemeName};
+    ///
+    /// let oled: Vec<_> = ThemeName::all()
+    ///     .iter()
+    ///     .filter(|t| t.recommended_display_brightness() == DisplayBrightness::Low)
+    ///     .collect();
+    ///
+    /// assert!(oled.contains(&&ThemeName::Cyberpunk));
+    /// assert_eq!(
+    ///     ThemeName::SolarizedLight.recommended_display_brightness(),
+    ///     DisplayBrightness::High
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn recommended_display_brightness(self) -> DisplayBrightness {
+        const BRIGHTNESS: [DisplayBrightness; ThemeName::COUNT] = theme_table! {
+            Dracula => DisplayBrightness::Medium,
+            OneDarkPro => DisplayBrightness::Medium,
+            Nord => DisplayBrightness::Medium,
+            CatppuccinMocha => DisplayBrightness::Low,
+            CatppuccinLatte => DisplayBrightness::High,
+            GruvboxDark => DisplayBrightness::Medium,
+            GruvboxLight => DisplayBrightness::High,
+            TokyoNight => DisplayBrightness::Low,
+            SolarizedDark => DisplayBrightness::Medium,
+            SolarizedLight => DisplayBrightness::High,
+            MonokaiPro => DisplayBrightness::Medium,
+            RosePine => DisplayBrightness::Low,
+            Kanagawa => DisplayBrightness::Low,
+            Everforest => DisplayBrightness::Medium,
+            Cyberpunk => DisplayBrightness::Low,
+        };
+        *self.lookup(&BRIGHTNESS)
+    }
 
-            // Nord: https://www.nordtheme.com
-            Self::Nord => ThemePalette {
-                accent: Color::Rgb(136, 192, 208),    // Frost blue
-                secondary: Color::Rgb(129, 161, 193), // Frost darker
-                bg: Color::Rgb(46, 52, 64),           // Polar Night
-                fg: Color::Rgb(236, 239, 244),        // Snow Storm
-                muted: Color::Rgb(76, 86, 106),       // Polar Night lighter
-                selection: Color::Rgb(67, 76, 94),    // Selection
-                error: Color::Rgb(191, 97, 106),      // Aurora red
-                warning: Color::Rgb(235, 203, 139),   // Aurora yellow
-                success: Color::Rgb(163, 190, 140),   // Aurora green
-                info: Color::Rgb(94, 129, 172),       // Frost
-            },
+    /// Returns the theme's stable numeric id.
+    ///
+    /// Unlike a theme's position in [`all()`](Self::all), the id is assigned
+    /// once and never changes or gets reused, even if themes are reordered or
+    /// added. This makes it suitable for compact binary formats. Convert back
+    /// with [`ThemeName::try_from`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let id = ThemeName::Nord.stable_id();
+    /// assert_eq!(ThemeName::try_from(id), Ok(ThemeName::Nord));
+    /// ```
+    #[must_use]
+    pub const fn stable_id(self) -> u16 {
+        const STABLE_IDS: [u16; ThemeName::COUNT] = theme_table! {
+            Dracula => 0,
+            OneDarkPro => 1,
+            Nord => 2,
+            CatppuccinMocha => 3,
+            CatppuccinLatte => 4,
+            GruvboxDark => 5,
+            GruvboxLight => 6,
+            TokyoNight => 7,
+            SolarizedDark => 8,
+            SolarizedLight => 9,
+            MonokaiPro => 10,
+            RosePine => 11,
+            Kanagawa => 12,
+            Everforest => 13,
+            Cyberpunk => 14,
+        };
+        *self.lookup(&STABLE_IDS)
+    }
 
-            // Catppuccin Mocha: https://catppuccin.com
-            Self::CatppuccinMocha => ThemePalette {
-                accent: Color::Rgb(137, 180, 250),    // Blue
-                secondary: Color::Rgb(245, 194, 231), // Pink
-                bg: Color::Rgb(30, 30, 46),           // Base
-                fg: Color::Rgb(205, 214, 244),        // Text
-                muted: Color::Rgb(108, 112, 134),     // Overlay0
-                selection: Color::Rgb(49, 50, 68),    // Surface0
-                error: Color::Rgb(243, 139, 168),     // Red
-                warning: Color::Rgb(249, 226, 175),   // Yellow
-                success: Color::Rgb(166, 227, 161),   // Green
-                info: Color::Rgb(148, 226, 213),      // Teal
-            },
+    /// Returns the theme's position in [`all()`](Self::all).
+    ///
+    /// Positions follow the current ordering, so they can shift when themes
+    /// are added; use [`stable_id()`](Self::stable_id) for anything
+    /// persisted. Convert back with [`from_index()`](Self::from_index).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::Nord.index(), 2);
+    /// assert_eq!(ThemeName::all()[ThemeName::Nord.index()], ThemeName::Nord);
+    /// ```
+    #[must_use]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
 
-            // Catppuccin Latte (light theme)
-            Self::CatppuccinLatte => ThemePalette {
-                accent: Color::Rgb(30, 102, 245),     // Blue
-                secondary: Color::Rgb(234, 118, 203), // Pink
-                bg: Color::Rgb(239, 241, 245),        // Base
-                fg: Color::Rgb(76, 79, 105),          // Text
-                muted: Color::Rgb(140, 143, 161),     // Overlay0
-                selection: Color::Rgb(204, 208, 218), // Surface0
-                error: Color::Rgb(210, 15, 57),       // Red
-                warning: Color::Rgb(223, 142, 29),    // Yellow
-                success: Color::Rgb(64, 160, 43),     // Green
-                info: Color::Rgb(23, 146, 153),       // Teal
-            },
+    /// Returns the theme at position `index` in [`all()`](Self::all).
+    ///
+    /// Returns `None` if `index` is not less than [`COUNT`](Self::COUNT).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::from_index(2), Some(ThemeName::Nord));
+    /// assert_eq!(ThemeName::from_index(ThemeName::COUNT), None);
+    /// ```
+    #[must_use]
+    pub const fn from_index(index: usize) -> Option<Self> {
+        if index < Self::COUNT {
+            Some(Self::all()[index])
+        } else {
+            None
+        }
+    }
 
-            // Gruvbox Dark: https://github.com/morhetz/gruvbox
-            Self::GruvboxDark => ThemePalette {
-                accent: Color::Rgb(250, 189, 47),     // Yellow
-                secondary: Color::Rgb(211, 134, 155), // Purple
-                bg: Color::Rgb(40, 40, 40),           // bg0
-                fg: Color::Rgb(235, 219, 178),        // fg
-                muted: Color::Rgb(146, 131, 116),     // gray
-                selection: Color::Rgb(80, 73, 69),    // bg2
+    /// Returns the family a theme belongs to.
+    ///
+    /// Light and dark variants of the same scheme share a family, so
+    /// [`GruvboxDark`](Self::GruvboxDark) and [`GruvboxLight`](Self::GruvboxLight)
+    /// both return `"Gruvbox"`. Themes without variants are their own family.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::CatppuccinMocha.family(), "Catppuccin");
+    /// assert_eq!(ThemeName::CatppuccinLatte.family(), "Catppuccin");
+    /// assert_eq!(ThemeName::Dracula.family(), "Dracula");
+    /// ```
+    #[must_use]
+    pub const fn family(self) -> &'static str {
+        const FAMILIES: [&str; ThemeName::COUNT] = theme_table! {
+            Dracula => "Dracula",
+            OneDarkPro => "One Dark",
+            Nord => "Nord",
+            CatppuccinMocha => "Catppuccin",
+            CatppuccinLatte => "Catppuccin",
+            GruvboxDark => "Gruvbox",
+            GruvboxLight => "Gruvbox",
+            TokyoNight => "Tokyo Night",
+            SolarizedDark => "Solarized",
+            SolarizedLight => "Solarized",
+            MonokaiPro => "Monokai",
+            RosePine => "Rosé Pine",
+            Kanagawa => "Kanagawa",
+            Everforest => "Everforest",
+            Cyberpunk => "Cyberpunk",
+        };
+        self.lookup::<&str>(&FAMILIES)
+    }
+
+    /// Returns curated, lowercase keywords describing the theme's look.
+    ///
+    /// Tags are meant for search boxes and filters ("pastel", "neon",
+    /// "retro", ...). Every theme's tags end with exactly one of `"light"`
+    /// or `"dark"`, agreeing with [`matches_appearance()`](Self::matches_appearance),
+    /// and no tag is repeated within a theme.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::Cyberpunk.tags(), ["neon", "high-contrast", "dark"]);
+    /// assert_eq!(ThemeName::GruvboxDark.tags(), ["retro", "warm", "dark"]);
+    ///
+    /// let pastel: Vec<ThemeName> = ThemeName::all()
+    ///     .iter()
+    ///     .copied()
+    ///     .filter(|theme| theme.tags().contains(&"pastel"))
+    ///     .collect();
+    /// assert!(pastel.contains(&ThemeName::CatppuccinLatte));
+    /// ```
+    #[must_use]
+    pub const fn tags(self) -> &'static [&'static str] {
+        const TAGS: [&[&str]; ThemeName::COUNT] = theme_table! {
+            Dracula => &["vibrant", "purple", "dark"],
+            OneDarkPro => &["balanced", "cool", "dark"],
+            Nord => &["arctic", "cool", "muted", "dark"],
+            CatppuccinMocha => &["pastel", "cozy", "dark"],
+            CatppuccinLatte => &["pastel", "cozy", "light"],
+            GruvboxDark => &["retro", "warm", "dark"],
+            GruvboxLight => &["retro", "warm", "light"],
+            TokyoNight => &["vibrant", "cool", "dark"],
+            SolarizedDark => &["classic", "low-contrast", "dark"],
+            SolarizedLight => &["classic", "low-contrast", "light"],
+            MonokaiPro => &["vibrant", "warm", "dark"],
+            RosePine => &["pastel", "muted", "dark"],
+            Kanagawa => &["muted", "warm", "dark"],
+            Everforest => &["nature", "green", "muted", "dark"],
+            Cyberpunk => &["neon", "high-contrast", "dark"],
+        };
+        self.lookup::<&[&str]>(&TAGS)
+    }
+
+    /// Returns the theme's sibling of the opposite appearance, if it has one.
+    ///
+    /// The sibling is the theme of the same [`family()`](Self::family) whose
+    /// background is light where this one is dark, or vice versa, so a
+    /// family header can offer a light/dark toggle. Themes without such a
+    /// sibling, like Dracula, return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::CatppuccinMocha.toggle_variant(), Some(ThemeName::CatppuccinLatte));
+    /// assert_eq!(ThemeName::SolarizedLight.toggle_variant(), Some(ThemeName::SolarizedDark));
+    /// assert_eq!(ThemeName::Dracula.toggle_variant(), None);
+    /// ```
+    #[must_use]
+    pub fn toggle_variant(self) -> Option<Self> {
+        let light = self.palette().is_light();
+        Self::all()
+            .iter()
+            .copied()
+            .find(|theme| theme.family() == self.family() && theme.palette().is_light() != light)
+    }
+
+    /// Returns the canonical upstream URL for the theme, if it has one.
+    ///
+    /// Useful for attribution in "About" screens. Returns `None` for
+    /// [`Cyberpunk`](Self::Cyberpunk), which is original to this crate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::Nord.source_url(), Some("https://www.nordtheme.com"));
+    /// assert_eq!(ThemeName::Cyberpunk.source_url(), None);
+    /// ```
+    #[must_use]
+    pub const fn source_url(self) -> Option<&'static str> {
+        const SOURCE_URLS: [Option<&str>; ThemeName::COUNT] = theme_table! {
+            Dracula => Some("https://draculatheme.com"),
+            OneDarkPro => Some("https://github.com/Binaryify/OneDark-Pro"),
+            Nord => Some("https://www.nordtheme.com"),
+            CatppuccinMocha => Some("https://catppuccin.com"),
+            CatppuccinLatte => Some("https://catppuccin.com"),
+            GruvboxDark => Some("https://github.com/morhetz/gruvbox"),
+            GruvboxLight => Some("https://github.com/morhetz/gruvbox"),
+            TokyoNight => Some("https://github.com/enkia/tokyo-night-vscode-theme"),
+            SolarizedDark => Some("https://ethanschoonover.com/solarized"),
+            SolarizedLight => Some("https://ethanschoonover.com/solarized"),
+            MonokaiPro => Some("https://monokai.pro"),
+            RosePine => Some("https://rosepinetheme.com"),
+            Kanagawa => Some("https://github.com/rebelot/kanagawa.nvim"),
+            Everforest => Some("https://github.com/sainnhe/everforest"),
+            Cyberpunk => None,
+        };
+        *self.lookup(&SOURCE_URLS)
+    }
+
+    /// Check whether the theme's appearance matches the requested mode.
+    ///
+    /// Returns `true` for light themes when `light` is `true` and for dark
+    /// themes when it is `false`, which reads cleanly in filters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let dark_mode = true;
+    /// let menu: Vec<ThemeName> = ThemeName::all()
+    ///     .iter()
+    ///     .copied()
+    ///     .filter(|t| t.matches_appearance(!dark_mode))
+    ///     .collect();
+    ///
+    /// assert!(menu.contains(&ThemeName::Nord));
+    /// assert!(!menu.contains(&ThemeName::GruvboxLight));
+    /// ```
+    #[must_use]
+    pub const fn matches_appearance(self, light: bool) -> bool {
+        self.palette().is_light() == light
+    }
+
+    /// Returns the themes of the given mode, in [`all()`](Self::all) order.
+    ///
+    /// A theme is light when [`ThemePalette::is_light()`] says so.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{ThemeMode, ThemeName};
+    ///
+    /// for mode in [ThemeMode::Light, ThemeMode::Dark] {
+    ///     println!("{mode:?} themes:");
+    ///     for theme in ThemeName::themes_by_mode(mode) {
+    ///         println!("  {theme}");
+    ///     }
+    /// }
+    /// assert_eq!(ThemeName::themes_by_mode(ThemeMode::Dark).count(), 12);
+    /// ```
+    pub fn themes_by_mode(mode: ThemeMode) -> impl Iterator<Item = Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .filter(move |theme| theme.matches_appearance(mode == ThemeMode::Light))
+    }
+
+    /// Returns every light theme, in [`all()`](Self::all) order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert!(ThemeName::light_themes().contains(&ThemeName::SolarizedLight));
+    /// ```
+    #[must_use]
+    pub fn light_themes() -> Vec<Self> {
+        Self::themes_by_mode(ThemeMode::Light).collect()
+    }
+
+    /// Returns every dark theme, in [`all()`](Self::all) order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::dark_themes()[0], ThemeName::Dracula);
+    /// ```
+    #[must_use]
+    pub fn dark_themes() -> Vec<Self> {
+        Self::themes_by_mode(ThemeMode::Dark).collect()
+    }
+
+    /// Returns the hue of the theme's accent color in degrees (`0.0..360.0`).
+    ///
+    /// The hue is measured on the HSL color wheel: red is `0.0`, green
+    /// `120.0` and blue `240.0`. An achromatic (gray) accent has no hue and
+    /// reports `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let hue = ThemeName::Cyberpunk.primary_hue();
+    /// assert!((hue - 180.0).abs() < 1.0); // neon cyan
+    /// ```
+    #[must_use]
+    pub fn primary_hue(self) -> f32 {
+        self.accent_hsl().map_or(0.0, |(h, _, _)| h)
+    }
+
+    /// Returns all themes sorted around the color wheel by [`primary_hue()`](Self::primary_hue).
+    ///
+    /// Themes run from red through yellow, green, cyan and blue to purple.
+    /// Themes whose accent is achromatic come last, in [`all()`](Self::all)
+    /// order, rather than being mixed in with the reds at hue `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let by_hue = ThemeName::all_by_hue();
+    /// assert_eq!(by_hue.len(), ThemeName::all().len());
+    /// assert_eq!(by_hue.last(), Some(&ThemeName::Dracula)); // purple
+    /// ```
+    #[must_use]
+    pub fn all_by_hue() -> Vec<Self> {
+        /// HSL saturation below which an accent is treated as gray.
+        const MIN_SATURATION: f32 = 0.1;
+
+        let mut themes = Self::all().to_vec();
+        themes.sort_by(|a, b| {
+            let key = |theme: &Self| match theme.accent_hsl() {
+                Some((h, s, _)) if s >= MIN_SATURATION => (false, h),
+                _ => (true, 0.0),
+            };
+            let (a_gray, a_hue) = key(a);
+            let (b_gray, b_hue) = key(b);
+            a_gray.cmp(&b_gray).then(a_hue.total_cmp(&b_hue))
+        });
+        themes
+    }
+
+    /// Grade the theme by its worst foreground color against the background.
+    ///
+    /// Where [`ThemePalette::contrast_grade`] only checks `fg` on `bg`, this
+    /// takes the lowest [`ContrastGrade`] of every color drawn as text on
+    /// `bg`: `fg`, `muted`, `accent`, `secondary`, `error`, `warning`,
+    /// `success` and `info`. A theme whose comments are hard to read therefore
+    /// grades low even if its body text is AAA. `ContrastGrade` is ordered from
+    /// [`Fail`](ContrastGrade::Fail) to [`Aaa`](ContrastGrade::Aaa), so the
+    /// result can be used to sort or filter themes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{ContrastGrade, ThemeName};
+    ///
+    /// let readable: Vec<ThemeName> = ThemeName::all()
+    ///     .iter()
+    ///     .copied()
+    ///     .filter(|t| t.overall_grade() >= ContrastGrade::AaLarge)
+    ///     .collect();
+    /// assert!(readable.contains(&ThemeName::Cyberpunk));
+    ///
+    /// // Dracula's body text is AAA, but its comment color is not.
+    /// assert!(ThemeName::Dracula.overall_grade() < ThemeName::Dracula.palette().contrast_grade());
+    /// ```
+    #[must_use]
+    pub fn overall_grade(self) -> ContrastGrade {
+        Self::palette_grade(&self.palette())
+    }
+
+    /// The lowest grade of any text color in `palette` against its `bg`.
+    fn palette_grade(palette: &ThemePalette) -> ContrastGrade {
+        [
+            palette.fg,
+            palette.muted,
+            palette.accent,
+            palette.secondary,
+            palette.error,
+            palette.warning,
+            palette.success,
+            palette.info,
+        ]
+        .into_iter()
+        .map(|c| ContrastGrade::from_ratio(color::contrast_ratio(c, palette.bg)))
+        .min()
+        .unwrap_or(ContrastGrade::Fail)
+    }
+
+    /// Returns the themes whose [`overall_grade()`](Self::overall_grade) is at least AA.
+    ///
+    /// Every color drawn as text on the background, including `muted`, has
+    /// a contrast ratio of at least 4.5:1, so these themes are a vetted
+    /// subset to offer low-vision users. The list is computed from the
+    /// palettes, in [`all()`](Self::all) order, so it stays accurate as they
+    /// change.
+    ///
+    /// The bar is high: the built-in themes keep their upstream colors, and
+    /// each of them currently has at least one color (usually the dimmed
+    /// `muted` comment color) below 4.5:1, so **the list is currently
+    /// empty**. Filter on [`ContrastGrade::AaLarge`] yourself for a broader
+    /// selection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{ContrastGrade, ThemeName};
+    ///
+    /// let accessible = ThemeName::accessible_themes();
+    /// assert!(accessible.iter().all(|t| t.overall_grade() >= ContrastGrade::Aa));
+    ///
+    /// // No built-in theme reaches AA for every text color yet.
+    /// assert!(accessible.is_empty());
+    /// ```
+    #[must_use]
+    pub fn accessible_themes() -> Vec<Self> {
+        Self::accessible_among(PALETTES.iter().copied())
+    }
+
+    /// The themes among `palettes` whose palette grades at least AA.
+    fn accessible_among(palettes: impl Iterator<Item = (Self, ThemePalette)>) -> Vec<Self> {
+        palettes
+            .filter(|(_, palette)| Self::palette_grade(palette) >= ContrastGrade::Aa)
+            .map(|(theme, _)| theme)
+            .collect()
+    }
+
+    /// HSL components of the accent color, if it resolves to RGB.
+    fn accent_hsl(self) -> Option<(f32, f32, f32)> {
+        color::to_rgb(self.palette().accent).map(|(r, g, b)| color::rgb_to_hsl(r, g, b))
+    }
+
+    /// Returns all metadata for this theme in a single struct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let meta = ThemeName::GruvboxLight.metadata();
+    /// assert_eq!(meta.slug, "gruvbox-light");
+    /// assert_eq!(meta.family, "Gruvbox");
+    /// assert!(meta.is_light);
+    /// ```
+    #[must_use]
+    pub fn metadata(self) -> ThemeMetadata {
+        let palette = self.palette();
+        ThemeMetadata {
+            name: self,
+            display_name: self.display_name(),
+            slug: self.slug(),
+            family: self.family(),
+            source_url: self.source_url(),
+            is_light: palette.is_light(),
+            contrast_grade: palette.contrast_grade(),
+            display_brightness: self.recommended_display_brightness(),
+        }
+    }
+
+    /// Returns an iterator over the metadata of every theme, in [`all()`](Self::all) order.
+    ///
+    /// This is a one-pass alternative to iterating [`all()`](Self::all) and
+    /// calling each accessor, handy for populating sortable theme tables.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let light: Vec<_> = ThemeName::catalog()
+    ///     .filter(|meta| meta.is_light)
+    ///     .map(|meta| meta.display_name)
+    ///     .collect();
+    ///
+    /// assert_eq!(light, ["Catppuccin Latte", "Gruvbox Light", "Solarized Light"]);
+    /// ```
+    pub fn catalog() -> impl Iterator<Item = ThemeMetadata> {
+        Self::all().iter().map(|&name| name.metadata())
+    }
+
+    /// Returns the next theme in the list, wrapping around at the end.
+    ///
+    /// Useful for implementing theme cycling with a "next theme" button.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let theme = ThemeName::Dracula;
+    /// assert_eq!(theme.next(), ThemeName::OneDarkPro);
+    ///
+    /// // Wraps around at the end
+    /// let last = ThemeName::Cyberpunk;
+    /// assert_eq!(last.next(), ThemeName::Dracula);
+    /// ```
+    #[must_use]
+    pub fn next(self) -> Self {
+        let themes = Self::all();
+        let current = themes.iter().position(|&t| t == self).unwrap_or(0);
+        themes[(current + 1) % themes.len()]
+    }
+
+    /// Returns the previous theme in the list, wrapping around at the beginning.
+    ///
+    /// Useful for implementing theme cycling with a "previous theme" button.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let theme = ThemeName::OneDarkPro;
+    /// assert_eq!(theme.prev(), ThemeName::Dracula);
+    ///
+    /// // Wraps around at the beginning
+    /// let first = ThemeName::Dracula;
+    /// assert_eq!(first.prev(), ThemeName::Cyberpunk);
+    /// ```
+    #[must_use]
+    pub fn prev(self) -> Self {
+        let themes = Self::all();
+        let current = themes.iter().position(|&t| t == self).unwrap_or(0);
+        themes[(current + themes.len() - 1) % themes.len()]
+    }
+
+    /// Returns the theme after this one in `subset`, wrapping around at the end.
+    ///
+    /// Like [`next()`](Self::next) but cycling only through `subset`, in the
+    /// slice's order, e.g. [`dark_themes()`](Self::dark_themes) for an app
+    /// that only supports dark themes. If this theme is not in `subset`, the
+    /// first entry is returned; an empty `subset` returns this theme.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let dark = ThemeName::dark_themes();
+    ///
+    /// // Skips over Catppuccin Latte.
+    /// assert_eq!(ThemeName::CatppuccinMocha.next_in(&dark), ThemeName::GruvboxDark);
+    /// // A theme outside the subset snaps to its first entry.
+    /// assert_eq!(ThemeName::CatppuccinLatte.next_in(&dark), ThemeName::Dracula);
+    /// ```
+    #[must_use]
+    pub fn next_in(self, subset: &[Self]) -> Self {
+        let Some(current) = subset.iter().position(|&t| t == self) else {
+            return subset.first().copied().unwrap_or(self);
+        };
+        subset[(current + 1) % subset.len()]
+    }
+
+    /// Returns the theme before this one in `subset`, wrapping around at the beginning.
+    ///
+    /// The reverse of [`next_in()`](Self::next_in), with the same fallbacks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let favorites = [ThemeName::Nord, ThemeName::Kanagawa, ThemeName::RosePine];
+    /// assert_eq!(ThemeName::Nord.prev_in(&favorites), ThemeName::RosePine);
+    /// assert_eq!(ThemeName::Dracula.prev_in(&favorites), ThemeName::Nord);
+    /// ```
+    #[must_use]
+    pub fn prev_in(self, subset: &[Self]) -> Self {
+        let Some(current) = subset.iter().position(|&t| t == self) else {
+            return subset.first().copied().unwrap_or(self);
+        };
+        subset[(current + subset.len() - 1) % subset.len()]
+    }
+
+    /// Returns the theme `delta` steps away in the list, wrapping around at both ends.
+    ///
+    /// Positive deltas move forward like [`next()`](Self::next), negative ones
+    /// backward like [`prev()`](Self::prev), and jumps larger than the list
+    /// wrap as many times as needed, which suits page-up/page-down and
+    /// momentum scrolling in pickers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::Dracula.at_offset(2), ThemeName::Nord);
+    /// assert_eq!(ThemeName::Dracula.at_offset(-1), ThemeName::Cyberpunk);
+    /// assert_eq!(ThemeName::Nord.at_offset(ThemeName::COUNT as isize), ThemeName::Nord);
+    ///
+    /// // Momentum scrolling
+    /// let velocity = 2.6_f32;
+    /// assert_eq!(ThemeName::Dracula.at_offset(velocity.round() as isize), ThemeName::CatppuccinMocha);
+    /// ```
+    #[must_use]
+    // `COUNT` is tiny, so it fits in `isize`, and `rem_euclid` is never negative.
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub const fn at_offset(self, delta: isize) -> Self {
+        let step = delta.rem_euclid(Self::COUNT as isize) as usize;
+        Self::all()[(self.index() + step) % Self::COUNT]
+    }
+
+    /// Returns the [`fingerprint`](ThemePalette::fingerprint) of this theme's palette.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::Nord.fingerprint(), ThemeName::Nord.palette().fingerprint());
+    /// ```
+    #[must_use]
+    pub const fn fingerprint(self) -> u64 {
+        self.palette().fingerprint()
+    }
+
+    /// Returns the first theme of the next family, wrapping around at the end.
+    ///
+    /// Skips past the remaining variants of the current [`family()`](Self::family),
+    /// which gives section-by-section navigation in pickers that group themes
+    /// by family.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// // Both Catppuccin variants are skipped in one step.
+    /// assert_eq!(ThemeName::Nord.next_family(), ThemeName::CatppuccinMocha);
+    /// assert_eq!(ThemeName::CatppuccinMocha.next_family(), ThemeName::GruvboxDark);
+    /// assert_eq!(ThemeName::CatppuccinLatte.next_family(), ThemeName::GruvboxDark);
+    ///
+    /// // Wraps around at the end
+    /// assert_eq!(ThemeName::Cyberpunk.next_family(), ThemeName::Dracula);
+    /// ```
+    #[must_use]
+    pub fn next_family(self) -> Self {
+        let mut theme = self.next();
+        while theme.family() == self.family() && theme != self {
+            theme = theme.next();
+        }
+        theme.family_head()
+    }
+
+    /// Returns the first theme of the previous family, wrapping around at the beginning.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::GruvboxLight.prev_family(), ThemeName::CatppuccinMocha);
+    /// assert_eq!(ThemeName::Dracula.prev_family(), ThemeName::Cyberpunk);
+    /// ```
+    #[must_use]
+    pub fn prev_family(self) -> Self {
+        self.family_head().prev().family_head()
+    }
+
+    /// Returns the first theme in [`all()`](Self::all) sharing this theme's family.
+    fn family_head(self) -> Self {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|theme| theme.family() == self.family())
+            .unwrap_or(self)
+    }
+
+    /// Returns a weighted blend of several themes' palettes.
+    ///
+    /// `weights` pairs up with `themes` by position; `None` weighs every
+    /// theme equally, like [`ThemePalette::average()`]. Weights are relative,
+    /// so `[3.0, 1.0]` and `[0.75, 0.25]` blend the same. Missing, negative
+    /// and NaN weights count as zero, and if no theme has a positive weight
+    /// they are all weighed equally. An empty `themes` slice returns
+    /// [`ThemePalette::default()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Color, ThemeName};
+    ///
+    /// let themes = [ThemeName::Nord, ThemeName::Dracula];
+    ///
+    /// let even = ThemeName::blend_themes(&themes, None);
+    /// assert_eq!(even.bg, Color::Rgb(43, 47, 59));
+    ///
+    /// let mostly_nord = ThemeName::blend_themes(&themes, Some(&[3.0, 1.0]));
+    /// assert_eq!(mostly_nord.bg, Color::Rgb(45, 50, 62));
+    /// ```
+    #[must_use]
+    pub fn blend_themes(themes: &[Self], weights: Option<&[f32]>) -> ThemePalette {
+        let weight_of = |i: usize| {
+            weights.map_or(1.0, |w| {
+                w.get(i).copied().filter(|w| *w > 0.0).unwrap_or(0.0)
+            })
+        };
+        let mut weighted: Vec<(ThemePalette, f32)> = themes
+            .iter()
+            .enumerate()
+            .map(|(i, theme)| (theme.palette(), weight_of(i)))
+            .collect();
+        if weighted.iter().all(|(_, w)| *w <= 0.0) {
+            for (_, w) in &mut weighted {
+                *w = 1.0;
+            }
+        }
+        ThemePalette::weighted_average(&weighted)
+    }
+
+    /// Returns this theme's entry in a per-theme table.
+    ///
+    /// `table` holds one value per theme in [`all()`](Self::all) order, so
+    /// associating data with every theme takes a single array instead of a
+    /// `match`, and the compiler rejects a table that is missing a theme.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// const STARS: [u8; ThemeName::COUNT] = [5, 4, 5, 5, 4, 4, 3, 5, 4, 3, 4, 5, 5, 4, 2];
+    ///
+    /// assert_eq!(*ThemeName::Dracula.lookup(&STARS), 5);
+    /// assert_eq!(*ThemeName::Cyberpunk.lookup(&STARS), 2);
+    /// ```
+    #[must_use]
+    pub const fn lookup<T>(self, table: &[T; Self::COUNT]) -> &T {
+        &table[self.index()]
+    }
+
+    /// Returns a reference to this theme's palette in static storage.
+    ///
+    /// All built-in palettes are compile-time constants, so they live in a
+    /// single `static` table. Passing `&'static ThemePalette` around avoids
+    /// copying the palette and re-running [`palette()`](Self::palette)'s
+    /// `match` in hot render loops; see the `palette_ref` benchmark.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{ThemeName, ThemePalette};
+    ///
+    /// let palette: &'static ThemePalette = ThemeName::Nord.palette_ref();
+    /// assert_eq!(*palette, ThemeName::Nord.palette());
+    /// ```
+    #[must_use]
+    pub const fn palette_ref(self) -> &'static ThemePalette {
+        &PALETTE_TABLE[self.index()]
+    }
+
+    /// Returns the color palette for this theme.
+    ///
+    /// The palette contains all the semantic colors you need to style your UI.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    /// use ratatui::style::Style;
+    ///
+    /// let palette = ThemeName::Nord.palette();
+    ///
+    /// // Use palette colors in your styles
+    /// let style = Style::default()
+    ///     .fg(palette.fg)
+    ///     .bg(palette.bg);
+    /// ```
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub const fn palette(self) -> ThemePalette {
+        match self {
+            // Dracula: https://draculatheme.com/contribute
+            Self::Dracula => ThemePalette {
+                accent: Color::Rgb(189, 147, 249),    // Purple
+                secondary: Color::Rgb(255, 121, 198), // Pink
+                bg: Color::Rgb(40, 42, 54),           // Background
+                fg: Color::Rgb(248, 248, 242),        // Foreground
+                muted: Color::Rgb(98, 114, 164),      // Comment
+                selection: Color::Rgb(68, 71, 90),    // Selection
+                error: Color::Rgb(255, 85, 85),       // Red
+                warning: Color::Rgb(255, 184, 108),   // Orange
+                success: Color::Rgb(80, 250, 123),    // Green
+                info: Color::Rgb(139, 233, 253),      // Cyan
+            },
+
+            // One Dark Pro: https://github.com/Binaryify/OneDark-Pro
+            Self::OneDarkPro => ThemePalette {
+                accent: Color::Rgb(97, 175, 239),     // Blue
+                secondary: Color::Rgb(198, 120, 221), // Magenta
+                bg: Color::Rgb(40, 44, 52),           // Background
+                fg: Color::Rgb(171, 178, 191),        // Foreground
+                muted: Color::Rgb(92, 99, 112),       // Comment
+                selection: Color::Rgb(62, 68, 81),    // Selection
+                error: Color::Rgb(224, 108, 117),     // Red
+                warning: Color::Rgb(229, 192, 123),   // Yellow
+                success: Color::Rgb(152, 195, 121),   // Green
+                info: Color::Rgb(86, 182, 194),       // Cyan
+            },
+
+            // Nord: https://www.nordtheme.com
+            Self::Nord => ThemePalette {
+                accent: Color::Rgb(136, 192, 208),    // Frost blue
+                secondary: Color::Rgb(129, 161, 193), // Frost darker
+                bg: Color::Rgb(46, 52, 64),           // Polar Night
+                fg: Color::Rgb(236, 239, 244),        // Snow Storm
+                muted: Color::Rgb(76, 86, 106),       // Polar Night lighter
+                selection: Color::Rgb(67, 76, 94),    // Selection
+                error: Color::Rgb(191, 97, 106),      // Aurora red
+                warning: Color::Rgb(235, 203, 139),   // Aurora yellow
+                success: Color::Rgb(163, 190, 140),   // Aurora green
+                info: Color::Rgb(94, 129, 172),       // Frost
+            },
+
+            // Catppuccin Mocha: https://catppuccin.com
+            Self::CatppuccinMocha => ThemePalette {
+                accent: Color::Rgb(137, 180, 250),    // Blue
+                secondary: Color::Rgb(245, 194, 231), // Pink
+                bg: Color::Rgb(30, 30, 46),           // Base
+                fg: Color::Rgb(205, 214, 244),        // Text
+                muted: Color::Rgb(108, 112, 134),     // Overlay0
+                selection: Color::Rgb(49, 50, 68),    // Surface0
+                error: Color::Rgb(243, 139, 168),     // Red
+                warning: Color::Rgb(249, 226, 175),   // Yellow
+                success: Color::Rgb(166, 227, 161),   // Green
+                info: Color::Rgb(148, 226, 213),      // Teal
+            },
+
+            // Catppuccin Latte (light theme)
+            Self::CatppuccinLatte => ThemePalette {
+                accent: Color::Rgb(30, 102, 245),     // Blue
+                secondary: Color::Rgb(234, 118, 203), // Pink
+                bg: Color::Rgb(239, 241, 245),        // Base
+                fg: Color::Rgb(76, 79, 105),          // Text
+                muted: Color::Rgb(140, 143, 161),     // Overlay0
+                selection: Color::Rgb(204, 208, 218), // Surface0
+                error: Color::Rgb(210, 15, 57),       // Red
+                warning: Color::Rgb(223, 142, 29),    // Yellow
+                success: Color::Rgb(64, 160, 43),     // Green
+                info: Color::Rgb(23, 146, 153),       // Teal
+            },
+
+            // Gruvbox Dark: https://github.com/morhetz/gruvbox
+            Self::GruvboxDark => ThemePalette {
+                accent: Color::Rgb(250, 189, 47),     // Yellow
+                secondary: Color::Rgb(211, 134, 155), // Purple
+                bg: Color::Rgb(40, 40, 40),           // bg0
+                fg: Color::Rgb(235, 219, 178),        // fg
+                muted: Color::Rgb(146, 131, 116),     // gray
+                selection: Color::Rgb(80, 73, 69),    // bg2
                 error: Color::Rgb(251, 73, 52),       // red
                 warning: Color::Rgb(254, 128, 25),    // orange
                 success: Color::Rgb(184, 187, 38),    // green
@@ -559,19 +1737,117 @@ impl ThemeName {
     }
 }
 
-impl std::fmt::Display for ThemeName {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// Every built-in theme paired with its palette, in [`ThemeName::all()`] order.
+///
+/// The table is built at compile time, so it can serve as a ready-made
+/// lookup table or feed `const` checks over all palettes.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_themes::{ThemeName, PALETTES};
+///
+/// let (name, palette) = PALETTES[2];
+/// assert_eq!(name, ThemeName::Nord);
+/// assert_eq!(palette, ThemeName::Nord.palette());
+///
+/// let light = PALETTES.iter().filter(|(_, palette)| palette.is_light()).count();
+/// assert_eq!(light, 3);
+/// ```
+pub const PALETTES: &[(ThemeName, ThemePalette)] = &{
+    let all = ThemeName::all();
+    let mut pairs = [(ThemeName::Dracula, ThemeName::Dracula.palette()); ThemeName::COUNT];
+    let mut i = 0;
+    while i < ThemeName::COUNT {
+        pairs[i] = (all[i], all[i].palette());
+        i += 1;
+    }
+    pairs
+};
+
+/// Every built-in palette, in declaration order (which matches [`ThemeName::all()`]).
+static PALETTE_TABLE: [ThemePalette; ThemeName::COUNT] = {
+    let all = ThemeName::all();
+    let mut palettes = [ThemeName::Dracula.palette(); ThemeName::COUNT];
+    let mut i = 0;
+    while i < ThemeName::COUNT {
+        palettes[i] = all[i].palette();
+        i += 1;
+    }
+    palettes
+};
+
+impl core::fmt::Display for ThemeName {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.display_name())
     }
 }
 
-impl std::str::FromStr for ThemeName {
+/// Error returned by [`ThemeName::parse`] for an unknown theme name.
+///
+/// Unlike the `String` error of [`FromStr`](core::str::FromStr), it needs no
+/// allocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ThemeNameParseError {
+    /// The name matches no theme.
+    Unknown,
+}
+
+impl core::fmt::Display for ThemeNameParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unknown => f.write_str("Unknown theme"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ThemeNameParseError {}
+
+/// Error returned when converting an unknown stable id into a [`ThemeName`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownThemeId(pub u16);
+
+impl core::fmt::Display for UnknownThemeId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Unknown theme id: {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownThemeId {}
+
+impl TryFrom<u16> for ThemeName {
+    type Error = UnknownThemeId;
+
+    /// Look up a theme by its [`stable_id()`](ThemeName::stable_id).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{ThemeName, UnknownThemeId};
+    ///
+    /// assert_eq!(ThemeName::try_from(0), Ok(ThemeName::Dracula));
+    /// assert_eq!(ThemeName::try_from(999), Err(UnknownThemeId(999)));
+    /// ```
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|theme| theme.stable_id() == id)
+            .ok_or(UnknownThemeId(id))
+    }
+}
+
+impl core::str::FromStr for ThemeName {
     type Err = String;
 
     /// Parse a theme name from a string.
     ///
     /// Accepts kebab-case (as used in serde/config files), `PascalCase`,
-    /// or lowercase names.
+    /// or lowercase names. The error for a near miss names the closest theme;
+    /// see [`ThemeName::parse_with_suggestion`].
     ///
     /// # Example
     ///
@@ -586,34 +1862,213 @@ impl std::str::FromStr for ThemeName {
     ///
     /// // Lowercase
     /// assert_eq!("dracula".parse::<ThemeName>().unwrap(), ThemeName::Dracula);
+    ///
+    /// // Typos get a hint
+    /// assert_eq!(
+    ///     "drakula".parse::<ThemeName>().unwrap_err(),
+    ///     "Unknown theme: drakula (did you mean 'dracula'?)"
+    /// );
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Normalize: lowercase and remove spaces/hyphens/underscores
-        let normalized: String = s
-            .to_lowercase()
-            .chars()
-            .filter(|c| c.is_alphanumeric())
-            .collect();
+        Self::parse_with_suggestion(s).map_err(|(input, suggestion)| {
+            log_fallback!("unknown theme name {input:?}");
+            let hint = suggestion
+                .map(|theme| format!(" (did you mean '{}'?)", theme.slug()))
+                .unwrap_or_default();
+            format!("Unknown theme: {input}{hint}")
+        })
+    }
+}
+
+impl TryFrom<&str> for ThemeName {
+    type Error = String;
+
+    /// Parse a theme name like [`FromStr`](core::str::FromStr).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// assert_eq!(ThemeName::try_from("Tokyo Night"), Ok(ThemeName::TokyoNight));
+    /// assert!(ThemeName::try_from("no-such-theme").is_err());
+    /// ```
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
+
+impl TryFrom<String> for ThemeName {
+    type Error = String;
+
+    /// Parse a theme name like [`FromStr`](core::str::FromStr).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::ThemeName;
+    ///
+    /// let name = String::from("gruvbox-dark");
+    /// assert_eq!(ThemeName::try_from(name), Ok(ThemeName::GruvboxDark));
+    /// ```
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
+
+/// Lowercase `s`, fold accents and drop everything but letters and digits,
+/// so that `"Rosé Pine"`, `"rose-pine"` and `"RosePine"` compare equal.
+fn normalize_name(s: &str) -> String {
+    s.to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .map(fold_diacritic)
+        .collect()
+}
+
+/// Map an accented Latin-1 letter to its unaccented ASCII letter.
+///
+/// Other characters are returned unchanged.
+const fn fold_diacritic(c: char) -> char {
+    match c {
+        'à'..='å' | 'À'..='Å' => 'a',
+        'ç' | 'Ç' => 'c',
+        'è'..='ë' | 'È'..='Ë' => 'e',
+        'ì'..='ï' | 'Ì'..='Ï' => 'i',
+        'ñ' | 'Ñ' => 'n',
+        'ò'..='ö' | 'Ò'..='Ö' | 'ø' | 'Ø' => 'o',
+        'ù'..='ü' | 'Ù'..='Ü' => 'u',
+        'ý' | 'ÿ' | 'Ý' => 'y',
+        _ => c,
+    }
+}
+
+/// Find the theme whose normalized slug is closest to `normalized`.
+///
+/// The allowed distance scales with the slug length (a third of it, at most
+/// three edits) so short slugs like `nord` don't attract unrelated input.
+fn suggest(normalized: &str) -> Option<ThemeName> {
+    ThemeName::all()
+        .iter()
+        .filter_map(|&theme| {
+            let slug = normalize_name(theme.slug());
+            let distance = levenshtein(normalized, &slug);
+            (distance <= (slug.len() / 3).min(3)).then_some((theme, distance))
+        })
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(theme, _)| theme)
+}
+
+/// Byte-wise equality usable in `const fn`.
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Ellipsize `text` to at most `max` terminal columns.
+///
+/// See [`ThemeName::display_name_truncated`] for the rules.
+pub(crate) fn truncate_to_width(text: &str, max: usize) -> Cow<'_, str> {
+    if text.width() <= max {
+        return Cow::Borrowed(text);
+    }
+    if max == 0 {
+        return Cow::Borrowed("");
+    }
+
+    // Reserve one column for the ellipsis.
+    let mut width = 0;
+    let mut end = 0;
+    for (i, c) in text.char_indices() {
+        width += c.width().unwrap_or(0);
+        if width > max - 1 {
+            break;
+        }
+        end = i + c.len_utf8();
+    }
+    Cow::Owned(format!("{}…", text[..end].trim_end()))
+}
 
-        match normalized.as_str() {
-            "dracula" => Ok(Self::Dracula),
-            "onedarkpro" | "onedark" => Ok(Self::OneDarkPro),
-            "nord" => Ok(Self::Nord),
-            "catppuccinmocha" | "mocha" => Ok(Self::CatppuccinMocha),
-            "catppuccinlatte" | "latte" => Ok(Self::CatppuccinLatte),
-            "gruvboxdark" | "gruvbox" => Ok(Self::GruvboxDark),
-            "gruvboxlight" => Ok(Self::GruvboxLight),
-            "tokyonight" | "tokyo" => Ok(Self::TokyoNight),
-            "solarizeddark" | "solarized" => Ok(Self::SolarizedDark),
-            "solarizedlight" => Ok(Self::SolarizedLight),
-            "monokaipro" | "monokai" => Ok(Self::MonokaiPro),
-            "rosepine" | "rose" => Ok(Self::RosePine),
-            "kanagawa" => Ok(Self::Kanagawa),
-            "everforest" => Ok(Self::Everforest),
-            "cyberpunk" => Ok(Self::Cyberpunk),
-            _ => Err(format!("Unknown theme: {s}")),
+/// Number of single-character insertions, deletions and substitutions
+/// needed to turn `a` into `b`.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(diagonal + 1).min(row[j] + 1);
         }
     }
+    row[b.len()]
+}
+
+/// Light or dark appearance, used to filter themes.
+///
+/// See [`ThemeName::themes_by_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ThemeMode {
+    /// Dark text on a light background.
+    Light,
+    /// Light text on a dark background.
+    Dark,
+}
+
+/// Display brightness a theme is meant to be viewed at.
+///
+/// Returned by [`ThemeName::recommended_display_brightness`]. Levels are
+/// ordered from `Low` to `High`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum DisplayBrightness {
+    /// A dimmed screen; suits near-black, OLED-friendly backgrounds.
+    Low,
+    /// Ordinary indoor brightness; suits most dark themes.
+    Medium,
+    /// A bright screen; needed for light backgrounds.
+    High,
+}
+
+/// Precomputed metadata describing a theme.
+///
+/// Returned by [`ThemeName::metadata`] and [`ThemeName::catalog`]. With the
+/// `serde` feature enabled the struct serializes to a flat object, so a whole
+/// catalog can be cached or exported in one call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ThemeMetadata {
+    /// The theme this metadata describes.
+    pub name: ThemeName,
+    /// Human-readable name, as returned by [`ThemeName::display_name`].
+    pub display_name: &'static str,
+    /// Kebab-case slug, as returned by [`ThemeName::slug`].
+    pub slug: &'static str,
+    /// Family name, as returned by [`ThemeName::family`].
+    pub family: &'static str,
+    /// Upstream URL, as returned by [`ThemeName::source_url`].
+    pub source_url: Option<&'static str>,
+    /// Whether the theme has a light background.
+    pub is_light: bool,
+    /// WCAG grade of the theme's foreground against its background.
+    pub contrast_grade: ContrastGrade,
+    /// Curated screen brightness, as returned by
+    /// [`ThemeName::recommended_display_brightness`].
+    pub display_brightness: DisplayBrightness,
 }
 
 /// A theme configuration wrapper providing convenient access to theme colors.
@@ -638,12 +2093,31 @@ impl std::str::FromStr for ThemeName {
 /// theme.next();
 /// assert_eq!(theme.name, ThemeName::CatppuccinMocha);
 /// ```
+///
+/// # Custom palettes
+///
+/// A theme created with [`Theme::custom`] uses its own [`ThemePalette`]
+/// instead of the one of [`name`](Self::name). The name then records the
+/// built-in theme the palette is based on, which still supplies metadata such
+/// as the [border type](ThemeName::recommended_border). Cycling with
+/// [`next()`](Self::next) and friends always lands on a built-in theme.
+///
+/// # Serialization
+///
+/// With the `serde` feature enabled, a built-in `Theme` serializes as its bare
+/// slug, so a config field reads `theme = "dracula"` rather than
+/// `theme = { name = "dracula" }`. The older struct form is still accepted
+/// when deserializing. A custom theme serializes as
+/// `{ name = "dracula", palette = { ... } }`, with the palette in its hex form.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Theme {
     /// The selected theme name.
-    #[cfg_attr(feature = "serde", serde(default))]
+    ///
+    /// For a [custom](Self::custom) theme this is the built-in theme its
+    /// palette is based on.
     pub name: ThemeName,
+    /// Palette replacing the one of `name`, for custom themes.
+    custom: Option<ThemePalette>,
 }
 
 impl Theme {
@@ -658,11 +2132,187 @@ impl Theme {
     /// ```
     #[must_use]
     pub const fn new(name: ThemeName) -> Self {
-        Self { name }
+        Self { name, custom: None }
+    }
+
+    /// Create a custom theme that uses `palette` instead of a built-in one.
+    ///
+    /// `base` names the built-in theme the palette is derived from (or closest
+    /// to). It becomes the theme's [`name`](Self::name) and supplies the
+    /// metadata a palette does not carry, such as the
+    /// [recommended border](ThemeName::recommended_border).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::Color;
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let palette = ThemeName::Nord.palette().with_accent(Color::Rgb(255, 0, 128));
+    /// let theme = Theme::custom(ThemeName::Nord, palette);
+    ///
+    /// assert_eq!(theme.name, ThemeName::Nord);
+    /// assert_eq!(theme.palette().accent, Color::Rgb(255, 0, 128));
+    /// assert!(!theme.is_builtin());
+    /// ```
+    #[must_use]
+    pub const fn custom(base: ThemeName, palette: ThemePalette) -> Self {
+        Self {
+            name: base,
+            custom: Some(palette),
+        }
+    }
+
+    /// Returns a copy of this theme with a different name.
+    ///
+    /// A non-mutating counterpart to assigning [`name`](Self::name), handy in
+    /// iterator chains and other code working with owned values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let theme = Theme::new(ThemeName::Dracula).with_name(ThemeName::Nord);
+    /// assert_eq!(theme.name, ThemeName::Nord);
+    /// ```
+    #[must_use]
+    pub const fn with_name(self, name: ThemeName) -> Self {
+        Self { name, ..self }
+    }
+
+    /// Returns a copy of this theme with its name transformed by `f`.
+    ///
+    /// A non-mutating counterpart to [`next()`](Self::next) and friends,
+    /// except that a custom palette is kept, as with
+    /// [`with_name()`](Self::with_name).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let themes: Vec<Theme> = [ThemeName::Dracula, ThemeName::Nord]
+    ///     .into_iter()
+    ///     .map(|name| Theme::new(name).map_name(ThemeName::next))
+    ///     .collect();
+    ///
+    /// assert_eq!(themes[0].name, ThemeName::OneDarkPro);
+    /// assert_eq!(themes[1].name, ThemeName::CatppuccinMocha);
+    /// ```
+    #[must_use]
+    pub fn map_name(self, f: impl FnOnce(ThemeName) -> ThemeName) -> Self {
+        Self {
+            name: f(self.name),
+            ..self
+        }
+    }
+
+    /// Describe how switching from this theme to `other` changes each color.
+    ///
+    /// Returns one sentence per palette field that differs, in field order,
+    /// naming both colors with [`ThemePalette::nearest_color_name`], e.g.
+    /// `"Accent changed from plum to sky blue"`. When both colors share the
+    /// nearest name the change is reported as slight instead. The names are
+    /// approximate, so this is meant for users, not for exact comparisons.
+    /// Identical themes yield an empty list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let current = Theme::new(ThemeName::GruvboxDark);
+    /// let preview = Theme::new(ThemeName::GruvboxLight);
+    /// for line in current.describe_difference(&preview) {
+    ///     println!("{line}");
+    /// }
+    ///
+    /// assert!(current.describe_difference(&current).is_empty());
+    /// ```
+    #[must_use]
+    pub fn describe_difference(&self, other: &Self) -> Vec<String> {
+        self.palette()
+            .diff(&other.palette())
+            .into_iter()
+            .map(|(field, before, after)| {
+                let label = match field {
+                    "bg" => "Background",
+                    "fg" => "Foreground",
+                    "accent" => "Accent",
+                    "secondary" => "Secondary",
+                    "muted" => "Muted",
+                    "selection" => "Selection",
+                    "error" => "Error",
+                    "warning" => "Warning",
+                    "success" => "Success",
+                    "info" => "Info",
+                    // A field without a label yet is named as is, never mislabeled.
+                    _ => field,
+                };
+                let before = ThemePalette::nearest_color_name(before);
+                let after = ThemePalette::nearest_color_name(after);
+                if before == after {
+                    format!("{label} changed slightly (still {before})")
+                } else {
+                    format!("{label} changed from {before} to {after}")
+                }
+            })
+            .collect()
+    }
+
+    /// Check whether this theme is one of the crate's built-in themes.
+    ///
+    /// Returns `false` for themes created with [`Theme::custom`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// assert!(Theme::new(ThemeName::Nord).is_builtin());
+    ///
+    /// let custom = Theme::custom(ThemeName::Nord, ThemeName::Dracula.palette());
+    /// assert!(!custom.is_builtin());
+    /// ```
+    #[must_use]
+    pub const fn is_builtin(&self) -> bool {
+        self.custom.is_none()
+    }
+
+    /// Returns the built-in theme this theme is, or `None` for a custom theme.
+    ///
+    /// Use it to show attribution or offer "reset to default" only for
+    /// built-in themes. A custom theme's base is still available as
+    /// [`name`](Self::name).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let theme = Theme::new(ThemeName::Kanagawa);
+    /// if let Some(name) = theme.builtin_name() {
+    ///     println!("Theme by {:?}", name.source_url());
+    /// }
+    /// assert_eq!(theme.builtin_name(), Some(ThemeName::Kanagawa));
+    ///
+    /// let custom = Theme::custom(ThemeName::Kanagawa, ThemeName::Nord.palette());
+    /// assert_eq!(custom.builtin_name(), None);
+    /// ```
+    #[must_use]
+    pub const fn builtin_name(&self) -> Option<ThemeName> {
+        if self.is_builtin() {
+            Some(self.name)
+        } else {
+            None
+        }
     }
 
     /// Returns the color palette for the current theme.
     ///
+    /// For a [custom](Self::custom) theme this is its own palette.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -678,7 +2328,235 @@ impl Theme {
     /// ```
     #[must_use]
     pub const fn palette(&self) -> ThemePalette {
-        self.name.palette()
+        match self.custom {
+            Some(palette) => palette,
+            None => self.name.palette(),
+        }
+    }
+
+    /// Returns the default cell style for this theme: `fg` on `bg`.
+    ///
+    /// Apply this to the whole frame before rendering anything else so that
+    /// cells your widgets never touch still use the theme colors instead of
+    /// the terminal's own foreground and background.
+    ///
+    /// ratatui's `Terminal` has no global default style, so the usual approach
+    /// is to paint the full area at the start of every frame, either directly
+    /// on the buffer or by rendering an empty [`Block`](ratatui::widgets::Block)
+    /// with this style.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect};
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let theme = Theme::new(ThemeName::Nord);
+    /// let palette = theme.palette();
+    ///
+    /// // Inside `terminal.draw(|frame| { ... })` this would be
+    /// // `frame.buffer_mut().set_style(frame.area(), theme.default_cell_style())`.
+    /// let area = Rect::new(0, 0, 10, 2);
+    /// let mut buf = Buffer::empty(area);
+    /// buf.set_style(area, theme.default_cell_style());
+    ///
+    /// assert_eq!(buf[(9, 1)].fg, palette.fg);
+    /// assert_eq!(buf[(9, 1)].bg, palette.bg);
+    /// ```
+    #[must_use]
+    pub const fn default_cell_style(&self) -> Style {
+        let palette = self.palette();
+        Style::new().fg(palette.fg).bg(palette.bg)
+    }
+
+    /// Style for a text cursor of the given shape.
+    ///
+    /// | Shape | Style |
+    /// |-------|-------|
+    /// | [`Block`](crate::CursorShape::Block) | reversed text colors: `bg` on `fg`, unlike the `selection` highlight |
+    /// | [`Line`](crate::CursorShape::Line) | `accent` foreground, for a bar glyph such as `│` |
+    /// | [`Underline`](crate::CursorShape::Underline) | underlined in `accent` |
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{CursorShape, Theme, ThemeName};
+    ///
+    /// let theme = Theme::new(ThemeName::Everforest);
+    /// let insert_mode = false;
+    /// let shape = if insert_mode { CursorShape::Line } else { CursorShape::Block };
+    ///
+    /// let style = theme.cursor_style(shape);
+    /// assert_eq!(style.fg, Some(theme.palette().bg));
+    /// assert_eq!(style.bg, Some(theme.palette().fg));
+    /// ```
+    #[cfg(feature = "widgets")]
+    #[must_use]
+    pub const fn cursor_style(&self, shape: crate::CursorShape) -> Style {
+        let palette = self.palette();
+        match shape {
+            crate::CursorShape::Block => Style::new().fg(palette.bg).bg(palette.fg),
+            crate::CursorShape::Line => Style::new().fg(palette.accent),
+            crate::CursorShape::Underline => Style::new()
+                .fg(palette.accent)
+                .add_modifier(Modifier::UNDERLINED),
+        }
+    }
+
+    /// Style for emphasized text: `accent` with the theme's
+    /// [recommended emphasis](ThemeName::recommended_emphasis).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{style::Modifier, text::Span};
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let theme = Theme::new(ThemeName::Kanagawa);
+    /// let highlight = Span::styled("important", theme.emphasis_style());
+    /// assert!(highlight.style.add_modifier.contains(Modifier::ITALIC));
+    /// ```
+    #[must_use]
+    pub const fn emphasis_style(&self) -> Style {
+        Style::new()
+            .fg(self.palette().accent)
+            .add_modifier(self.name.recommended_emphasis())
+    }
+
+    /// Styles for keybinding hints: `(key, description)`.
+    ///
+    /// Keys are bold `accent` and descriptions `muted`, so footers like
+    /// `q quit  ? help` look the same across an app. The `Legend` widget
+    /// (`widgets` feature) renders a whole row of hints with these styles.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::text::{Line, Span};
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let (key, description) = Theme::new(ThemeName::Nord).legend_styles();
+    /// let hint = Line::from(vec![
+    ///     Span::styled("q", key),
+    ///     Span::styled(" quit", description),
+    /// ]);
+    /// ```
+    #[must_use]
+    pub const fn legend_styles(&self) -> (Style, Style) {
+        let palette = self.palette();
+        (
+            Style::new().fg(palette.accent).add_modifier(Modifier::BOLD),
+            Style::new().fg(palette.muted),
+        )
+    }
+
+    /// Style for a [`Gauge`](ratatui::widgets::Gauge) label drawn over the accent fill.
+    ///
+    /// Gauges center their label across both the `accent` fill and the
+    /// unfilled `bg` track, so the label can straddle the two. This style
+    /// picks the first of [`ThemePalette::on_accent`], `fg`, black and white
+    /// that reaches 4.5:1 contrast against `accent` and 3:1 against `bg`. If
+    /// none does, it uses `on_accent` on an `accent` background, so the label
+    /// sits on its own patch and percentages stay legible on every theme.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{text::Span, widgets::Gauge};
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let theme = Theme::new(ThemeName::Nord);
+    /// let palette = theme.palette();
+    ///
+    /// let gauge = Gauge::default()
+    ///     .gauge_style(palette.accent)
+    ///     .label(Span::styled("42%", theme.gauge_label_style()))
+    ///     .ratio(0.42);
+    /// ```
+    #[cfg(feature = "widgets")]
+    #[must_use]
+    pub fn gauge_label_style(&self) -> Style {
+        let palette = self.palette();
+        let legible = |c: Color| {
+            color::contrast_ratio(c, palette.accent) >= 4.5
+                && color::contrast_ratio(c, palette.bg) >= 3.0
+        };
+        let on_accent = palette.on_accent();
+        [
+            on_accent,
+            palette.fg,
+            Color::Rgb(0, 0, 0),
+            Color::Rgb(255, 255, 255),
+        ]
+        .into_iter()
+        .find(|&c| legible(c))
+        .map_or_else(
+            || Style::new().fg(on_accent).bg(palette.accent),
+            |c| Style::new().fg(c),
+        )
+    }
+
+    /// Returns a bordered [`Block`](ratatui::widgets::Block) styled for this theme.
+    ///
+    /// The block uses the theme's [recommended border type](ThemeName::recommended_border),
+    /// `muted` borders and a bold `accent` title style. Chain further builder
+    /// calls to add a title or override any of these.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{BorderType, Paragraph};
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let theme = Theme::new(ThemeName::RosePine);
+    /// let panel = Paragraph::new("hello").block(theme.block().title("Greeting"));
+    ///
+    /// // Users can still pick their own border.
+    /// let plain = theme.block().border_type(BorderType::Plain);
+    /// ```
+    #[cfg(feature = "widgets")]
+    #[must_use]
+    pub fn block(&self) -> ratatui::widgets::Block<'static> {
+        let palette = self.palette();
+        ratatui::widgets::Block::bordered()
+            .border_type(self.name.recommended_border())
+            .border_style(Style::new().fg(palette.muted))
+            .title_style(Style::new().fg(palette.accent).add_modifier(Modifier::BOLD))
+    }
+
+    /// Clear `area` to blank cells in the theme's `fg` on `bg`.
+    ///
+    /// Every cell in `area` is reset to a space, dropping any previous
+    /// symbol, colors and modifiers, then given the
+    /// [default cell style](Self::default_cell_style). Call it before drawing
+    /// a panel's content so the panel has a solid themed background instead
+    /// of showing the terminal default or whatever was drawn there before.
+    /// Parts of `area` outside the buffer are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect};
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let theme = Theme::new(ThemeName::Kanagawa);
+    /// let mut buf = Buffer::with_lines(["stale", "stale"]);
+    ///
+    /// let panel = Rect::new(1, 0, 3, 2);
+    /// theme.fill_background(panel, &mut buf);
+    ///
+    /// assert_eq!(buf[(2, 1)].symbol(), " ");
+    /// assert_eq!(buf[(2, 1)].bg, theme.palette().bg);
+    /// assert_eq!(buf[(0, 1)].symbol(), "s");
+    /// ```
+    #[cfg(feature = "widgets")]
+    pub fn fill_background(&self, area: ratatui::layout::Rect, buf: &mut ratatui::buffer::Buffer) {
+        let style = self.default_cell_style();
+        for position in area.intersection(buf.area).positions() {
+            let cell = &mut buf[position];
+            cell.reset();
+            cell.set_style(style);
+        }
     }
 
     /// Check if this is a light theme.
@@ -692,7 +2570,7 @@ impl Theme {
     /// assert!(theme.is_light());
     /// ```
     #[must_use]
-    pub fn is_light(&self) -> bool {
+    pub const fn is_light(&self) -> bool {
         self.palette().is_light()
     }
 
@@ -707,7 +2585,7 @@ impl Theme {
     /// assert!(theme.is_dark());
     /// ```
     #[must_use]
-    pub fn is_dark(&self) -> bool {
+    pub const fn is_dark(&self) -> bool {
         self.palette().is_dark()
     }
 
@@ -723,7 +2601,7 @@ impl Theme {
     /// assert_eq!(theme.name, ThemeName::OneDarkPro);
     /// ```
     pub fn next(&mut self) {
-        self.name = self.name.next();
+        *self = Self::new(self.name.next());
     }
 
     /// Cycle to the previous theme in the list.
@@ -738,7 +2616,81 @@ impl Theme {
     /// assert_eq!(theme.name, ThemeName::Dracula);
     /// ```
     pub fn prev(&mut self) {
-        self.name = self.name.prev();
+        *self = Self::new(self.name.prev());
+    }
+
+    /// Jump to the first theme of the next family.
+    ///
+    /// See [`ThemeName::next_family`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let mut theme = Theme::new(ThemeName::CatppuccinMocha);
+    /// theme.next_family();
+    /// assert_eq!(theme.name, ThemeName::GruvboxDark);
+    /// ```
+    pub fn next_family(&mut self) {
+        *self = Self::new(self.name.next_family());
+    }
+
+    /// Jump to the first theme of the previous family.
+    ///
+    /// See [`ThemeName::prev_family`].
+    pub fn prev_family(&mut self) {
+        *self = Self::new(self.name.prev_family());
+    }
+
+    /// Switch to the preferred theme for the opposite appearance.
+    ///
+    /// Light themes switch to `dark_pref` and dark themes to `light_pref`,
+    /// based on [`is_light()`](Self::is_light). The preferred themes need not
+    /// share a family with the current one, which suits a single hotkey (or an
+    /// OS appearance change) that flips between a user's chosen themes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let mut theme = Theme::new(ThemeName::Dracula);
+    ///
+    /// theme.toggle_appearance(ThemeName::Nord, ThemeName::SolarizedLight);
+    /// assert_eq!(theme.name, ThemeName::SolarizedLight);
+    ///
+    /// theme.toggle_appearance(ThemeName::Nord, ThemeName::SolarizedLight);
+    /// assert_eq!(theme.name, ThemeName::Nord);
+    /// ```
+    pub fn toggle_appearance(&mut self, dark_pref: ThemeName, light_pref: ThemeName) {
+        *self = Self::new(if self.is_light() {
+            dark_pref
+        } else {
+            light_pref
+        });
+    }
+
+    /// Check whether this theme differs from `previous`.
+    ///
+    /// Equivalent to `self != previous`, but reads better at redraw sites
+    /// that compare the theme from the last frame with the current one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_themes::{Theme, ThemeName};
+    ///
+    /// let previous = Theme::new(ThemeName::Nord);
+    /// let mut current = previous;
+    /// assert!(!current.has_changed_since(&previous));
+    ///
+    /// current.next();
+    /// assert!(current.has_changed_since(&previous));
+    /// ```
+    #[must_use]
+    pub fn has_changed_since(&self, previous: &Self) -> bool {
+        self != previous
     }
 }
 
@@ -748,15 +2700,78 @@ impl From<ThemeName> for Theme {
     }
 }
 
-impl std::fmt::Display for Theme {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.name)
+impl core::fmt::Display for Theme {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_builtin() {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{} (custom)", self.name)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ThemeName {
+    /// Parses the name like [`FromStr`](core::str::FromStr), so display names
+    /// and other spellings are accepted alongside the kebab-case slug.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = Cow::<'de, str>::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Theme {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        /// The struct form, used only for custom themes.
+        #[derive(Serialize)]
+        struct Custom<'a> {
+            name: ThemeName,
+            palette: &'a ThemePalette,
+        }
+
+        match &self.custom {
+            Some(palette) => Custom {
+                name: self.name,
+                palette,
+            }
+            .serialize(serializer),
+            None => self.name.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Theme {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Accepted representations: the bare slug, or the `{ name, palette }`
+        /// struct, where a `palette` makes the theme custom.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Slug(ThemeName),
+            Struct {
+                #[serde(default)]
+                name: ThemeName,
+                #[serde(default)]
+                palette: Option<ThemePalette>,
+            },
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Slug(name) => Ok(Self::new(name)),
+            Repr::Struct { name, palette } => Ok(Self {
+                name,
+                custom: palette,
+            }),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::{collections::BTreeSet, vec};
 
     #[test]
     fn test_all_themes_have_palettes() {
@@ -849,6 +2864,754 @@ mod tests {
         assert_eq!(Theme::default().name, ThemeName::Dracula);
     }
 
+    #[test]
+    fn test_default_cell_style() {
+        let theme = Theme::new(ThemeName::GruvboxLight);
+        let palette = theme.palette();
+        assert_eq!(
+            theme.default_cell_style(),
+            Style::default().fg(palette.fg).bg(palette.bg)
+        );
+    }
+
+    #[test]
+    fn test_catalog_matches_accessors() {
+        let catalog: Vec<_> = ThemeName::catalog().collect();
+        assert_eq!(catalog.len(), ThemeName::all().len());
+        for (meta, &theme) in catalog.iter().zip(ThemeName::all()) {
+            assert_eq!(meta.name, theme);
+            assert_eq!(meta.slug, theme.slug());
+            assert_eq!(meta.family, theme.family());
+            assert_eq!(meta.source_url, theme.source_url());
+            assert_eq!(meta.is_light, theme.palette().is_light());
+            assert_eq!(
+                meta.display_brightness,
+                theme.recommended_display_brightness()
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_theme_serializes_as_slug() {
+        let theme = Theme::new(ThemeName::TokyoNight);
+        let json = serde_json::to_string(&theme).unwrap();
+        assert_eq!(json, r#""tokyo-night""#);
+        assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), theme);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_theme_deserializes_legacy_struct() {
+        let theme: Theme = serde_json::from_str(r#"{ "name": "nord" }"#).unwrap();
+        assert_eq!(theme.name, ThemeName::Nord);
+
+        let theme: Theme = serde_json::from_str("{}").unwrap();
+        assert_eq!(theme.name, ThemeName::Dracula);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_custom_theme_serde_round_trip() {
+        let palette = ThemeName::Nord.palette().with_accent(Color::Rgb(1, 2, 3));
+        let theme = Theme::custom(ThemeName::Nord, palette);
+        let json = serde_json::to_string(&theme).unwrap();
+        assert!(json.starts_with(r#"{"name":"nord","palette":{"#));
+        assert!(json.contains(r##""accent":"#010203""##));
+        assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), theme);
+    }
+
+    #[test]
+    fn test_builtin_themes_are_builtin() {
+        for &name in ThemeName::all() {
+            let theme = Theme::new(name);
+            assert!(theme.is_builtin());
+            assert_eq!(theme.builtin_name(), Some(name));
+        }
+    }
+
+    #[test]
+    fn test_custom_theme_is_not_builtin() {
+        // Even a palette identical to the base's is custom: provenance, not
+        // colors, decides.
+        for palette in [
+            ThemeName::Nord.palette(),
+            ThemeName::Nord.palette().with_accent(Color::Rgb(1, 2, 3)),
+        ] {
+            let theme = Theme::custom(ThemeName::Nord, palette);
+            assert!(!theme.is_builtin());
+            assert_eq!(theme.builtin_name(), None);
+            assert_eq!(theme.name, ThemeName::Nord);
+            assert_eq!(theme.palette(), palette);
+            assert_ne!(theme, Theme::new(ThemeName::Nord));
+        }
+    }
+
+    #[test]
+    fn test_custom_theme_cycles_to_builtin() {
+        let custom = Theme::custom(ThemeName::Nord, ThemeName::Dracula.palette());
+        let mut theme = custom;
+        theme.next();
+        assert_eq!(theme, Theme::new(ThemeName::CatppuccinMocha));
+        assert!(theme.is_builtin());
+
+        let renamed = custom.with_name(ThemeName::Kanagawa);
+        assert!(!renamed.is_builtin());
+        assert_eq!(renamed.palette(), ThemeName::Dracula.palette());
+        assert_eq!(custom.to_string(), "Nord (custom)");
+    }
+
+    #[test]
+    fn test_stable_id_round_trip() {
+        for &theme in ThemeName::all() {
+            assert_eq!(ThemeName::try_from(theme.stable_id()), Ok(theme));
+        }
+        assert_eq!(ThemeName::try_from(u16::MAX), Err(UnknownThemeId(u16::MAX)));
+    }
+
+    #[test]
+    fn test_family_cycling_visits_each_family_once() {
+        let mut theme = ThemeName::Dracula;
+        let mut families = Vec::new();
+        loop {
+            families.push(theme.family());
+            theme = theme.next_family();
+            if theme == ThemeName::Dracula {
+                break;
+            }
+        }
+        assert_eq!(families.len(), 12);
+
+        for _ in 0..families.len() {
+            theme = theme.prev_family();
+        }
+        assert_eq!(theme, ThemeName::Dracula);
+    }
+
+    #[cfg(feature = "widgets")]
+    #[test]
+    fn test_gauge_label_is_legible_on_accent() {
+        for &name in ThemeName::all() {
+            let theme = Theme::new(name);
+            let palette = theme.palette();
+            let style = theme.gauge_label_style();
+            let label = style.fg.unwrap();
+            let ratio = crate::color::contrast_ratio(label, palette.accent);
+            assert!(ratio >= 4.5, "{name}: label contrast {ratio:.2}");
+
+            // The label also covers the unfilled track unless it brings its own background.
+            let track = style.bg.unwrap_or(palette.bg);
+            let on_track = crate::color::contrast_ratio(label, track);
+            assert!(on_track >= 3.0, "{name}: label on track {on_track:.2}");
+            assert!(
+                style.bg.is_none() || style.bg == Some(palette.accent),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_abbreviations_are_unique_and_short() {
+        let mut seen = BTreeSet::new();
+        for theme in ThemeName::all() {
+            let abbreviation = theme.abbreviation();
+            assert!(abbreviation.len() <= 5, "{theme}: {abbreviation}");
+            assert!(seen.insert(abbreviation), "duplicate {abbreviation}");
+        }
+    }
+
+    #[test]
+    fn test_toggle_appearance_crosses_families() {
+        let mut theme = Theme::new(ThemeName::CatppuccinLatte);
+        theme.toggle_appearance(ThemeName::TokyoNight, ThemeName::GruvboxLight);
+        assert_eq!(theme.name, ThemeName::TokyoNight);
+        theme.toggle_appearance(ThemeName::TokyoNight, ThemeName::GruvboxLight);
+        assert_eq!(theme.name, ThemeName::GruvboxLight);
+    }
+
+    #[test]
+    fn test_all_by_hue_orders_around_the_wheel() {
+        let by_hue = ThemeName::all_by_hue();
+        let position = |theme| by_hue.iter().position(|&t| t == theme).unwrap();
+
+        // Rose < yellow < green < cyan < blue < purple.
+        assert!(position(ThemeName::RosePine) < position(ThemeName::GruvboxDark));
+        assert!(position(ThemeName::GruvboxDark) < position(ThemeName::Everforest));
+        assert!(position(ThemeName::Everforest) < position(ThemeName::Cyberpunk));
+        assert!(position(ThemeName::Cyberpunk) < position(ThemeName::TokyoNight));
+        assert!(position(ThemeName::TokyoNight) < position(ThemeName::Dracula));
+
+        let hues: Vec<f32> = by_hue.iter().map(|t| t.primary_hue()).collect();
+        assert!(hues.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[cfg(feature = "widgets")]
+    #[test]
+    fn test_block_uses_recommended_border() {
+        use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::empty(area);
+        Theme::new(ThemeName::CatppuccinLatte)
+            .block()
+            .render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "╭");
+        assert_eq!(buf[(0, 0)].fg, ThemeName::CatppuccinLatte.palette().muted);
+
+        let mut buf = Buffer::empty(area);
+        Theme::new(ThemeName::Nord).block().render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "┌");
+    }
+
+    #[test]
+    fn test_matches_appearance_partitions_themes() {
+        for &theme in ThemeName::all() {
+            assert_ne!(
+                theme.matches_appearance(true),
+                theme.matches_appearance(false)
+            );
+        }
+        assert!(ThemeName::CatppuccinLatte.matches_appearance(true));
+        assert!(ThemeName::Dracula.matches_appearance(false));
+    }
+
+    #[test]
+    fn test_name_arrays_line_up_with_all() {
+        assert_eq!(ThemeName::COUNT, ThemeName::all().len());
+        let (names, slugs) = (ThemeName::display_names(), ThemeName::slugs());
+        for (i, theme) in ThemeName::all().iter().enumerate() {
+            assert_eq!(names[i], theme.display_name());
+            assert_eq!(slugs[i], theme.slug());
+        }
+    }
+
+    #[test]
+    fn test_index_round_trips() {
+        for (i, &theme) in ThemeName::all().iter().enumerate() {
+            assert_eq!(theme.index(), i);
+            assert_eq!(ThemeName::from_index(theme.index()), Some(theme));
+        }
+        assert_eq!(ThemeName::from_index(ThemeName::COUNT), None);
+        assert_eq!(ThemeName::from_index(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_palettes_table_matches_all() {
+        assert_eq!(PALETTES.len(), ThemeName::all().len());
+        for (&(name, palette), &theme) in PALETTES.iter().zip(ThemeName::all()) {
+            assert_eq!(name, theme);
+            assert_eq!(palette, name.palette());
+        }
+    }
+
+    #[test]
+    fn test_palette_ref_matches_palette() {
+        for (i, &theme) in ThemeName::all().iter().enumerate() {
+            assert_eq!(theme as usize, i);
+            assert_eq!(*theme.palette_ref(), theme.palette());
+        }
+    }
+
+    #[test]
+    fn test_parse_suggests_close_slugs_only() {
+        assert_eq!(levenshtein("drakula", "dracula"), 1);
+        assert_eq!(levenshtein("", "nord"), 4);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+
+        let err = "drakula".parse::<ThemeName>().unwrap_err();
+        assert_eq!(err, "Unknown theme: drakula (did you mean 'dracula'?)");
+        assert_eq!(
+            ThemeName::parse_with_suggestion("Tokyo Nite"),
+            Err(("Tokyo Nite".to_string(), Some(ThemeName::TokyoNight)))
+        );
+        assert_eq!(
+            ThemeName::parse_with_suggestion("gruvbox-lite"),
+            Err(("gruvbox-lite".to_string(), Some(ThemeName::GruvboxLight)))
+        );
+
+        // Short or wildly different input gets no misleading hint.
+        assert_eq!(
+            "foo".parse::<ThemeName>().unwrap_err(),
+            "Unknown theme: foo"
+        );
+        assert_eq!(
+            ThemeName::parse_with_suggestion("nerd").unwrap_err().1,
+            Some(ThemeName::Nord)
+        );
+        assert_eq!(
+            ThemeName::parse_with_suggestion("xyzzy").unwrap_err().1,
+            None
+        );
+    }
+
+    #[test]
+    fn test_has_changed_since() {
+        let nord = Theme::new(ThemeName::Nord);
+        assert!(!nord.has_changed_since(&Theme::new(ThemeName::Nord)));
+        assert!(nord.has_changed_since(&Theme::new(ThemeName::Dracula)));
+    }
+
+    #[test]
+    fn test_legend_styles() {
+        let palette = ThemeName::Everforest.palette();
+        let (key, description) = Theme::new(ThemeName::Everforest).legend_styles();
+        assert_eq!(key.fg, Some(palette.accent));
+        assert!(key.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(description.fg, Some(palette.muted));
+    }
+
+    #[test]
+    fn test_parse_matches_from_str_without_allocating() {
+        for &theme in ThemeName::all() {
+            assert_eq!(ThemeName::parse(theme.slug()), Ok(theme));
+            assert_eq!(ThemeName::parse(&theme.slug().to_uppercase()), Ok(theme));
+        }
+        for &theme in ThemeName::all() {
+            assert_eq!(theme.display_name().parse(), Ok(theme));
+            assert_eq!(ThemeName::parse(theme.display_name()), Ok(theme));
+        }
+        assert_eq!(ThemeName::parse("ROSÉ PINE"), Ok(ThemeName::RosePine));
+        for input in [
+            "Tokyo Night",
+            "tokyonight",
+            "GRUVBOX",
+            "one_dark",
+            "",
+            "x",
+            "drakula",
+        ] {
+            assert_eq!(ThemeName::parse(input).ok(), input.parse().ok(), "{input}");
+        }
+        assert_eq!(
+            ThemeName::parse("catppuccin-mocha-but-much-longer"),
+            Err(ThemeNameParseError::Unknown)
+        );
+        assert_eq!(ThemeNameParseError::Unknown.to_string(), "Unknown theme");
+    }
+
+    #[test]
+    fn test_try_from_str_matches_from_str() {
+        for &theme in ThemeName::all() {
+            assert_eq!(ThemeName::try_from(theme.slug()), Ok(theme));
+            assert_eq!(ThemeName::try_from(theme.slug().to_string()), Ok(theme));
+        }
+        for name in ["Tokyo Night", "tokyonight", "TokyoNight"] {
+            assert_eq!(ThemeName::try_from(name), Ok(ThemeName::TokyoNight));
+        }
+        assert_eq!(
+            ThemeName::try_from(String::from("drakula")),
+            Err("Unknown theme: drakula (did you mean 'dracula'?)".to_string())
+        );
+        assert_eq!(
+            ThemeName::try_from("foo"),
+            Err("Unknown theme: foo".to_string())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_from_error_converts_to_box_error() {
+        fn load(name: &str) -> Result<ThemeName, Box<dyn std::error::Error>> {
+            Ok(ThemeName::try_from(name)?)
+        }
+
+        assert_eq!(load("nord").unwrap(), ThemeName::Nord);
+        assert!(load("foo").is_err());
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            ThemeName::parse_list(" tokyo-night,,kanagawa ,???,tokyo night"),
+            vec![ThemeName::TokyoNight, ThemeName::Kanagawa]
+        );
+        assert!(ThemeName::parse_list("").is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_env() {
+        // The variable name is unique to this test, so parallel tests don't race.
+        let var = "RATATUI_THEMES_TEST_PARSE_ENV";
+        assert!(ThemeName::parse_env(var).is_empty());
+        std::env::set_var(var, "dracula,nord");
+        assert_eq!(
+            ThemeName::parse_env(var),
+            vec![ThemeName::Dracula, ThemeName::Nord]
+        );
+        std::env::remove_var(var);
+    }
+
+    #[test]
+    fn test_overall_grade_uses_worst_color() {
+        assert_eq!(ThemeName::Dracula.overall_grade(), ContrastGrade::AaLarge);
+        assert_eq!(
+            ThemeName::GruvboxLight.overall_grade(),
+            ContrastGrade::AaLarge
+        );
+        // Nord's muted comment color is the weak spot despite AAA body text.
+        assert_eq!(ThemeName::Nord.overall_grade(), ContrastGrade::Fail);
+        assert_eq!(
+            ThemeName::Nord.palette().contrast_grade(),
+            ContrastGrade::Aaa
+        );
+
+        for &theme in ThemeName::all() {
+            assert!(theme.overall_grade() <= theme.palette().contrast_grade());
+        }
+        assert!(ContrastGrade::Fail < ContrastGrade::AaLarge);
+        assert!(ContrastGrade::Aa < ContrastGrade::Aaa);
+    }
+
+    #[cfg(feature = "widgets")]
+    #[test]
+    fn test_cursor_style_per_shape() {
+        use crate::CursorShape;
+
+        let theme = Theme::new(ThemeName::Dracula);
+        let palette = theme.palette();
+
+        let block = theme.cursor_style(CursorShape::Block);
+        assert_eq!((block.fg, block.bg), (Some(palette.bg), Some(palette.fg)));
+        for &name in ThemeName::all() {
+            let theme = Theme::new(name);
+            let block = theme.cursor_style(CursorShape::Block);
+            assert_ne!(block.bg, theme.palette().selected_style().bg, "{name}");
+        }
+
+        let line = theme.cursor_style(CursorShape::Line);
+        assert_eq!((line.fg, line.bg), (Some(palette.accent), None));
+
+        let underline = theme.cursor_style(CursorShape::Underline);
+        assert_eq!(underline.fg, Some(palette.accent));
+        assert!(underline.add_modifier.contains(Modifier::UNDERLINED));
+        assert_eq!(CursorShape::default(), CursorShape::Block);
+    }
+
+    #[test]
+    fn test_describe_difference() {
+        let dracula = Theme::new(ThemeName::Dracula);
+        let lines = dracula.describe_difference(&Theme::new(ThemeName::Nord));
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[0], "Accent changed from plum to sky blue");
+        let labels = [
+            "Accent",
+            "Secondary",
+            "Background",
+            "Foreground",
+            "Muted",
+            "Selection",
+            "Error",
+            "Warning",
+            "Success",
+            "Info",
+        ];
+        for (line, label) in lines.iter().zip(labels) {
+            assert!(line.starts_with(&format!("{label} changed")), "{line}");
+        }
+        assert_eq!(
+            lines[2],
+            "Background changed slightly (still dark slate gray)"
+        );
+
+        // Gruvbox Dark and Light share their muted gray, so it isn't mentioned.
+        let lines = Theme::new(ThemeName::GruvboxDark)
+            .describe_difference(&Theme::new(ThemeName::GruvboxLight));
+        assert_eq!(lines.len(), 9);
+        assert!(lines.iter().all(|line| !line.starts_with("Muted")));
+
+        assert!(dracula.describe_difference(&dracula).is_empty());
+    }
+
+    #[test]
+    fn test_emphasis_style_uses_recommended_modifier() {
+        for &name in ThemeName::all() {
+            let style = Theme::new(name).emphasis_style();
+            assert_eq!(style.fg, Some(name.palette().accent));
+            assert_eq!(style.add_modifier, name.recommended_emphasis());
+        }
+        assert_eq!(ThemeName::Dracula.recommended_emphasis(), Modifier::BOLD);
+        assert_eq!(
+            ThemeName::MonokaiPro.recommended_emphasis(),
+            Modifier::ITALIC
+        );
+        assert_eq!(ThemeName::Nord.recommended_emphasis(), Modifier::empty());
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_wrap)]
+    fn test_at_offset_wraps_in_both_directions() {
+        let count = ThemeName::COUNT as isize;
+        for &theme in ThemeName::all() {
+            assert_eq!(theme.at_offset(0), theme);
+            assert_eq!(theme.at_offset(1), theme.next());
+            assert_eq!(theme.at_offset(-1), theme.prev());
+            assert_eq!(theme.at_offset(count), theme);
+            assert_eq!(theme.at_offset(-count), theme);
+            assert_eq!(theme.at_offset(count * 3 + 2), theme.next().next());
+            assert_eq!(theme.at_offset(-count * 2 - 5), theme.at_offset(-5));
+        }
+        assert_eq!(ThemeName::Kanagawa.at_offset(4), ThemeName::OneDarkPro);
+        assert_eq!(ThemeName::OneDarkPro.at_offset(-4), ThemeName::Kanagawa);
+        assert_eq!(
+            ThemeName::Dracula.at_offset(isize::MIN),
+            ThemeName::Dracula.at_offset(isize::MIN % count)
+        );
+        assert_eq!(
+            ThemeName::Dracula.at_offset(isize::MAX).at_offset(1),
+            ThemeName::Dracula.at_offset(isize::MAX % count + 1)
+        );
+    }
+
+    #[test]
+    fn test_icons_are_distinct_emoji() {
+        let icons: Vec<char> = ThemeName::all().iter().map(|t| t.icon()).collect();
+        for (i, icon) in icons.iter().enumerate() {
+            assert!(!icon.is_ascii() && !icon.is_whitespace(), "{icon:?}");
+            assert!(!icons[..i].contains(icon), "duplicate icon {icon}");
+        }
+    }
+
+    #[test]
+    fn test_tags_match_appearance() {
+        for theme in ThemeName::all() {
+            let tags = theme.tags();
+            let appearance = if theme.palette().is_light() {
+                "light"
+            } else {
+                "dark"
+            };
+            assert_eq!(tags.last(), Some(&appearance), "{theme}");
+            assert!(!tags[..tags.len() - 1].contains(&"light"), "{theme}");
+            assert!(!tags[..tags.len() - 1].contains(&"dark"), "{theme}");
+            for (i, tag) in tags.iter().enumerate() {
+                assert!(!tags[..i].contains(tag), "{theme}: duplicate tag {tag}");
+                assert!(tag.bytes().all(|b| b.is_ascii_lowercase() || b == b'-'));
+            }
+        }
+    }
+
+    #[cfg(feature = "widgets")]
+    #[test]
+    fn test_fill_background_clears_only_area() {
+        use ratatui::{buffer::Buffer, layout::Rect, style::Modifier};
+
+        let theme = Theme::new(ThemeName::GruvboxLight);
+        let palette = theme.palette();
+        let mut buf = Buffer::with_lines(["abcd", "efgh", "ijkl"]);
+        buf.set_style(
+            buf.area,
+            Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+        );
+
+        // Extends past the buffer on purpose.
+        theme.fill_background(Rect::new(2, 1, 10, 10), &mut buf);
+
+        for (x, y) in [(2, 1), (3, 1), (2, 2), (3, 2)] {
+            let cell = &buf[(x, y)];
+            assert_eq!(cell.symbol(), " ");
+            assert_eq!((cell.fg, cell.bg), (palette.fg, palette.bg));
+            assert!(cell.modifier.is_empty());
+        }
+        assert_eq!(buf[(1, 1)].symbol(), "f");
+        assert_eq!(buf[(2, 0)].fg, Color::Red);
+    }
+
+    #[test]
+    fn test_display_brightness_follows_background() {
+        for &theme in ThemeName::all() {
+            let brightness = theme.recommended_display_brightness();
+            assert_eq!(
+                brightness == DisplayBrightness::High,
+                theme.palette().is_light(),
+                "{theme}"
+            );
+        }
+        assert_eq!(
+            ThemeName::Cyberpunk.recommended_display_brightness(),
+            DisplayBrightness::Low
+        );
+        assert!(DisplayBrightness::Low < DisplayBrightness::High);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_theme_name_serde_accepts_display_names() {
+        for &theme in ThemeName::all() {
+            let json = serde_json::to_string(&theme).unwrap();
+            assert_eq!(json, format!("\"{}\"", theme.slug()));
+            assert_eq!(serde_json::from_str::<ThemeName>(&json).unwrap(), theme);
+
+            let display = serde_json::to_string(theme.display_name()).unwrap();
+            assert_eq!(serde_json::from_str::<ThemeName>(&display).unwrap(), theme);
+        }
+
+        for spelling in ["Tokyo Night", "tokyonight", "tokyo-night"] {
+            let json = format!("\"{spelling}\"");
+            assert_eq!(
+                serde_json::from_str::<ThemeName>(&json).unwrap(),
+                ThemeName::TokyoNight
+            );
+        }
+
+        let err = serde_json::from_str::<ThemeName>(r#""drakula""#).unwrap_err();
+        assert!(err.to_string().contains("did you mean 'dracula'?"), "{err}");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_display_brightness_serde() {
+        let json = serde_json::to_string(&DisplayBrightness::Medium).unwrap();
+        assert_eq!(json, r#""medium""#);
+        assert_eq!(
+            serde_json::from_str::<DisplayBrightness>(r#""low""#).unwrap(),
+            DisplayBrightness::Low
+        );
+    }
+
+    #[test]
+    fn test_blend_themes_weights() {
+        let themes = [ThemeName::GruvboxDark, ThemeName::GruvboxLight];
+        let (dark, light) = (themes[0].palette(), themes[1].palette());
+
+        assert_eq!(ThemeName::blend_themes(&themes, Some(&[1.0, 0.0])), dark);
+        assert_eq!(ThemeName::blend_themes(&themes, Some(&[2.0])), dark);
+        assert_eq!(ThemeName::blend_themes(&themes, Some(&[-1.0, 5.0])), light);
+        assert_eq!(
+            ThemeName::blend_themes(&themes, Some(&[f32::NAN, 0.0])),
+            ThemeName::blend_themes(&themes, None)
+        );
+        assert_eq!(
+            ThemeName::blend_themes(&themes, None),
+            ThemePalette::average(&[dark, light])
+        );
+        assert_eq!(ThemeName::blend_themes(&[], None), ThemePalette::default());
+    }
+
+    #[test]
+    fn test_accessible_themes_meet_aa() {
+        // Every built-in theme has at least one text color below 4.5:1.
+        assert_eq!(ThemeName::accessible_themes(), []);
+        // Nord's body text passes, but its comment color does not.
+        assert!(ThemeName::Nord.palette().contrast_grade() >= ContrastGrade::Aa);
+        assert!(ThemeName::Nord.overall_grade() < ContrastGrade::Aa);
+
+        let readable = ThemePalette {
+            accent: Color::Rgb(120, 170, 255),
+            secondary: Color::Rgb(200, 150, 255),
+            bg: Color::Rgb(0, 0, 0),
+            fg: Color::Rgb(230, 230, 230),
+            muted: Color::Rgb(170, 170, 170),
+            selection: Color::Rgb(60, 60, 60),
+            error: Color::Rgb(255, 110, 110),
+            warning: Color::Rgb(255, 210, 90),
+            success: Color::Rgb(110, 220, 120),
+            info: Color::Rgb(100, 200, 255),
+        };
+        let dim_comments = ThemePalette {
+            muted: Color::Rgb(60, 60, 60),
+            ..readable
+        };
+        let candidates = [
+            (ThemeName::Nord, ThemeName::Nord.palette()),
+            (ThemeName::Kanagawa, readable),
+            (ThemeName::Everforest, dim_comments),
+        ];
+        assert_eq!(
+            ThemeName::accessible_among(candidates.into_iter()),
+            [ThemeName::Kanagawa]
+        );
+    }
+
+    #[test]
+    fn test_display_name_truncated_counts_display_width() {
+        use unicode_width::UnicodeWidthStr;
+
+        // "Rosé Pine" is 10 bytes but 9 columns wide.
+        assert_eq!(ThemeName::RosePine.display_name_truncated(9), "Rosé Pine");
+        assert_eq!(ThemeName::RosePine.display_name_truncated(8), "Rosé Pi…");
+        // The trailing space before the cut is dropped.
+        assert_eq!(ThemeName::RosePine.display_name_truncated(6), "Rosé…");
+        assert_eq!(ThemeName::Dracula.display_name_truncated(1), "…");
+        assert_eq!(ThemeName::Dracula.display_name_truncated(0), "");
+
+        for &theme in ThemeName::all() {
+            for max in 0..20 {
+                let truncated = theme.display_name_truncated(max);
+                assert!(truncated.width() <= max, "{theme} at {max}: {truncated}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_slug_round_trips() {
+        for &theme in ThemeName::all() {
+            assert_eq!(ThemeName::from_slug(theme.slug()), Some(theme));
+        }
+        for input in ["Dracula", "onedark", " nord", "catppuccin_mocha", ""] {
+            assert_eq!(ThemeName::from_slug(input), None, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_themes_by_mode_partitions_all() {
+        assert_eq!(
+            ThemeName::light_themes(),
+            [
+                ThemeName::CatppuccinLatte,
+                ThemeName::GruvboxLight,
+                ThemeName::SolarizedLight
+            ]
+        );
+        let dark = ThemeName::dark_themes();
+        assert_eq!(dark.len() + 3, ThemeName::COUNT);
+        assert!(dark.iter().all(|t| !t.palette().is_light()));
+    }
+
+    #[test]
+    fn test_cycling_in_dark_subset_stays_dark() {
+        let dark = ThemeName::dark_themes();
+        let mut forward = ThemeName::Dracula;
+        let mut backward = ThemeName::Dracula;
+        for _ in 0..dark.len() * 2 {
+            forward = forward.next_in(&dark);
+            backward = backward.prev_in(&dark);
+            assert!(!forward.palette().is_light(), "{forward}");
+            assert!(!backward.palette().is_light(), "{backward}");
+        }
+        assert_eq!(forward, ThemeName::Dracula);
+        assert_eq!(ThemeName::GruvboxLight.prev_in(&dark), dark[0]);
+        assert_eq!(ThemeName::Nord.next_in(&[]), ThemeName::Nord);
+        assert_eq!(ThemeName::Nord.next_in(&[ThemeName::Nord]), ThemeName::Nord);
+    }
+
+    #[test]
+    fn test_source_url_for_every_upstream_theme() {
+        for &theme in ThemeName::all() {
+            let url = theme.source_url();
+            if theme == ThemeName::Cyberpunk {
+                assert_eq!(url, None);
+            } else {
+                assert!(url.is_some_and(|u| u.starts_with("https://")), "{theme}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_toggle_variant_pairs_siblings() {
+        assert_eq!(
+            ThemeName::GruvboxDark.toggle_variant(),
+            Some(ThemeName::GruvboxLight)
+        );
+        assert_eq!(ThemeName::Dracula.toggle_variant(), None);
+        for &theme in ThemeName::all() {
+            if let Some(sibling) = theme.toggle_variant() {
+                assert_eq!(sibling.family(), theme.family());
+                assert_eq!(sibling.toggle_variant(), Some(theme));
+            }
+        }
+    }
+
     #[test]
     fn test_theme_count() {
         assert_eq!(ThemeName::all().len(), 15);
@@ -0,0 +1,907 @@
+//! Ready-made ratatui widgets styled by a theme.
+//!
+//! This module is available with the `widgets` feature (enabled by default).
+//! It contains [`ThemePicker`], a preview panel showing a theme's palette,
+//! [`GradientLine`], a single-row background gradient for headers,
+//! [`Legend`], a row of keybinding hints, [`ThemeList`], a scrollable
+//! theme chooser, and [`ThemeDemo`], a mock application screen for
+//! evaluating a theme. [`CursorShape`] selects a
+//! themed cursor style for editor-like widgets.
+
+use crate::color;
+use crate::palette::{NotificationLevel, ThemePalette};
+use crate::theme::{truncate_to_width, Theme, ThemeName};
+use alloc::{format, vec, vec::Vec};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, Gauge, List, ListItem, Paragraph, Widget},
+};
+
+/// A preview panel showing a theme's name and a swatch for every palette color.
+///
+/// The panel is drawn as a bordered block titled with the theme's display name,
+/// followed by one line per palette field. Call [`compare`](Self::compare) to
+/// show a second theme side by side for A/B comparison. Palettes that are not
+/// built in, such as ones made with [`ThemePaletteBuilder`](crate::ThemePaletteBuilder)
+/// or loaded at runtime, can be previewed with [`from_palette`](Self::from_palette),
+/// [`custom`](Self::custom) and [`compare_custom`](Self::compare_custom).
+///
+/// By default each panel clears its whole area and fills it with the
+/// palette's background and foreground, so it looks the same on any terminal
+/// and no glyphs from a previous frame show through; see
+/// [`opaque`](Self::opaque) to draw over the existing cells instead.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+/// use ratatui_themes::{ThemeName, ThemePicker};
+///
+/// let area = Rect::new(0, 0, 40, 12);
+/// let mut buf = Buffer::empty(area);
+///
+/// ThemePicker::new(ThemeName::GruvboxDark)
+///     .compare(ThemeName::GruvboxLight)
+///     .render(area, &mut buf);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThemePicker<'a> {
+    theme: Preview<'a>,
+    compare: Option<Preview<'a>>,
+    opaque: bool,
+}
+
+/// A palette and the title it is shown under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Preview<'a> {
+    name: &'a str,
+    palette: ThemePalette,
+}
+
+impl Preview<'_> {
+    const fn builtin(theme: ThemeName) -> Self {
+        Self {
+            name: theme.display_name(),
+            palette: theme.palette(),
+        }
+    }
+}
+
+impl Default for ThemePicker<'_> {
+    fn default() -> Self {
+        Self::new(ThemeName::default())
+    }
+}
+
+impl<'a> ThemePicker<'a> {
+    /// Minimum width of one column in comparison mode.
+    ///
+    /// When the area is narrower than two columns of this width, only the
+    /// primary theme is rendered.
+    pub const MIN_COLUMN_WIDTH: u16 = 16;
+
+    /// Title used for a palette given without a name.
+    pub const CUSTOM_LABEL: &'static str = "Custom";
+
+    /// Create a picker previewing `theme`.
+    #[must_use]
+    pub const fn new(theme: ThemeName) -> Self {
+        Self {
+            theme: Preview::builtin(theme),
+            compare: None,
+            opaque: true,
+        }
+    }
+
+    /// Create a picker previewing `palette`, titled [`CUSTOM_LABEL`](Self::CUSTOM_LABEL).
+    ///
+    /// Use [`custom`](Self::custom) to give the palette its own title.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+    /// use ratatui_themes::{ThemeName, ThemePicker};
+    ///
+    /// // e.g. a palette loaded from a config file
+    /// let loaded = ThemeName::Kanagawa.palette().tint(10, 0, -10);
+    ///
+    /// let area = Rect::new(0, 0, 24, 12);
+    /// let mut buf = Buffer::empty(area);
+    /// ThemePicker::from_palette(loaded).render(area, &mut buf);
+    ///
+    /// let title: String = (0..24).map(|x| buf[(x, 0)].symbol()).collect();
+    /// assert!(title.contains("Custom"));
+    /// ```
+    #[must_use]
+    pub const fn from_palette(palette: ThemePalette) -> Self {
+        Self::custom(Self::CUSTOM_LABEL, palette)
+    }
+
+    /// Create a picker previewing a custom `palette`, titled `name`.
+    ///
+    /// The name is borrowed, so it can come from runtime data such as a
+    /// config file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+    /// use ratatui_themes::{Color, ThemeName, ThemePaletteBuilder, ThemePicker};
+    ///
+    /// let brand = ThemePaletteBuilder::from_palette(ThemeName::Nord.palette())
+    ///     .accent(Color::Rgb(0, 120, 212))
+    ///     .build_checked()
+    ///     .unwrap();
+    ///
+    /// let area = Rect::new(0, 0, 40, 12);
+    /// let mut buf = Buffer::empty(area);
+    /// ThemePicker::custom("Acme", brand)
+    ///     .compare(ThemeName::Nord)
+    ///     .render(area, &mut buf);
+    ///
+    /// assert_eq!(buf[(1, 1)].fg, Color::Rgb(0, 120, 212));
+    /// ```
+    #[must_use]
+    pub const fn custom(name: &'a str, palette: ThemePalette) -> Self {
+        Self {
+            theme: Preview { name, palette },
+            compare: None,
+            opaque: true,
+        }
+    }
+
+    /// Render `other` next to the primary theme in a second column.
+    ///
+    /// The area is split into two equal columns, each headed by its theme's
+    /// name. Falls back to the primary theme alone when the area is narrower
+    /// than twice [`MIN_COLUMN_WIDTH`](Self::MIN_COLUMN_WIDTH).
+    #[must_use]
+    pub const fn compare(mut self, other: ThemeName) -> Self {
+        self.compare = Some(Preview::builtin(other));
+        self
+    }
+
+    /// Render a custom `palette`, titled `name`, next to the primary theme.
+    ///
+    /// Behaves like [`compare`](Self::compare).
+    #[must_use]
+    pub const fn compare_custom(mut self, name: &'a str, palette: ThemePalette) -> Self {
+        self.compare = Some(Preview { name, palette });
+        self
+    }
+
+    /// Set whether each panel fills its area with the palette's colors.
+    ///
+    /// Defaults to `true`, which clears every cell in the area before
+    /// drawing. With `false`, only the swatch lines are styled and the rest of
+    /// the area keeps whatever the buffer already had, e.g. to let a
+    /// surrounding background show through.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
+    /// use ratatui_themes::{ThemeName, ThemePicker};
+    ///
+    /// let area = Rect::new(0, 0, 24, 12);
+    /// let mut buf = Buffer::empty(area);
+    /// ThemePicker::new(ThemeName::Nord).opaque(false).render(area, &mut buf);
+    ///
+    /// assert_eq!(buf[(0, 0)].bg, Color::Reset);
+    /// ```
+    #[must_use]
+    pub const fn opaque(mut self, opaque: bool) -> Self {
+        self.opaque = opaque;
+        self
+    }
+
+    /// Render a single theme's panel into `area`.
+    fn render_theme(preview: Preview<'_>, opaque: bool, area: Rect, buf: &mut Buffer) {
+        let palette = preview.palette;
+        let text = Style::default().fg(palette.fg).bg(palette.bg);
+        if opaque {
+            Clear.render(area, buf);
+            buf.set_style(area, text);
+        }
+
+        let block = Block::bordered()
+            .title(Span::styled(
+                truncate_to_width(preview.name, usize::from(area.width.saturating_sub(2))),
+                palette.title_style(),
+            ))
+            .border_style(Style::default().fg(palette.accent));
+
+        let lines: Vec<Line<'_>> = palette
+            .iter()
+            .map(|(name, color)| {
+                Line::from(vec![
+                    Span::styled("██", Style::default().fg(color)),
+                    Span::styled(format!(" {name}"), text),
+                ])
+            })
+            .collect();
+
+        Paragraph::new(lines).block(block).render(area, buf);
+    }
+}
+
+impl Widget for ThemePicker<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &ThemePicker<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        match self.compare {
+            Some(other) if area.width >= ThemePicker::MIN_COLUMN_WIDTH * 2 => {
+                let [left, right] =
+                    Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(area);
+                ThemePicker::render_theme(self.theme, self.opaque, left, buf);
+                ThemePicker::render_theme(other, self.opaque, right, buf);
+            }
+            _ => ThemePicker::render_theme(self.theme, self.opaque, area, buf),
+        }
+    }
+}
+
+/// A single row filled with a horizontal background gradient.
+///
+/// Each cell's background is interpolated between the start and end colors,
+/// while its symbol and foreground are left alone, so render the gradient
+/// first and draw the header text on top. Areas narrower than
+/// [`MIN_WIDTH`](Self::MIN_WIDTH) are filled with the start color instead.
+/// Only the first row of the area is drawn.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::{Paragraph, Widget}};
+/// use ratatui_themes::{GradientLine, ThemeName};
+///
+/// let palette = ThemeName::TokyoNight.palette();
+/// let area = Rect::new(0, 0, 30, 1);
+/// let mut buf = Buffer::empty(area);
+///
+/// GradientLine::from(palette.header_gradient()).render(area, &mut buf);
+/// Paragraph::new(" My App").style(palette.fg).render(area, &mut buf);
+///
+/// assert_eq!(buf[(29, 0)].bg, palette.bg);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GradientLine {
+    start: Color,
+    end: Color,
+}
+
+impl GradientLine {
+    /// Narrowest area that gets a gradient; narrower areas are filled solid.
+    pub const MIN_WIDTH: u16 = 4;
+
+    /// Create a gradient from `start` (left edge) to `end` (right edge).
+    #[must_use]
+    pub const fn new(start: Color, end: Color) -> Self {
+        Self { start, end }
+    }
+}
+
+impl From<(Color, Color)> for GradientLine {
+    fn from((start, end): (Color, Color)) -> Self {
+        Self::new(start, end)
+    }
+}
+
+impl Widget for GradientLine {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &GradientLine {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        let last = area.width - 1;
+        for offset in 0..area.width {
+            let bg = if area.width < GradientLine::MIN_WIDTH {
+                self.start
+            } else {
+                color::blend(self.start, self.end, f32::from(offset) / f32::from(last))
+            };
+            buf[(area.x + offset, area.y)].set_bg(bg);
+        }
+    }
+}
+
+/// A mock application screen showing how a theme looks in a realistic layout.
+///
+/// The demo fills the whole area with the theme's background and draws:
+///
+/// - a title bar on a [header gradient](crate::ThemePalette::header_gradient),
+/// - a list with one selected item,
+/// - a status message in each semantic color,
+/// - a gauge with a [legible label](Theme::gauge_label_style),
+/// - a [`Legend`] footer with key hints.
+///
+/// Drop it into a full-screen area to preview a theme before applying it.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+/// use ratatui_themes::{ThemeDemo, ThemeName};
+///
+/// let area = Rect::new(0, 0, 60, 16);
+/// let mut buf = Buffer::empty(area);
+/// ThemeDemo::new(ThemeName::Kanagawa).render(area, &mut buf);
+///
+/// assert_eq!(buf[(59, 15)].bg, ThemeName::Kanagawa.palette().bg);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ThemeDemo {
+    theme: ThemeName,
+}
+
+impl ThemeDemo {
+    /// Create a demo screen styled with `theme`.
+    #[must_use]
+    pub const fn new(theme: ThemeName) -> Self {
+        Self { theme }
+    }
+}
+
+impl Widget for ThemeDemo {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &ThemeDemo {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let theme = Theme::new(self.theme);
+        let palette = theme.palette();
+        buf.set_style(area, theme.default_cell_style());
+
+        let [header, body, gauge, footer] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(3),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+        let [list_area, status_area] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(body);
+
+        GradientLine::from(palette.header_gradient()).render(header, buf);
+        Line::from(vec![
+            Span::styled(
+                format!(" {} ", self.theme.display_name()),
+                Style::new().fg(palette.accent).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("— theme preview", Style::new().fg(palette.muted)),
+        ])
+        .render(header, buf);
+
+        let items = ["Inbox", "Drafts", "Sent", "Archive"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, label)| {
+                if i == 1 {
+                    ListItem::new(format!("> {label}")).style(
+                        Style::new()
+                            .fg(palette.on_selection())
+                            .bg(palette.selection),
+                    )
+                } else {
+                    ListItem::new(format!("  {label}"))
+                }
+            });
+        List::new(items)
+            .block(theme.block().title("Folders"))
+            .render(list_area, buf);
+
+        let messages = [
+            (NotificationLevel::Info, "Sync scheduled"),
+            (NotificationLevel::Success, "Build passed"),
+            (NotificationLevel::Warning, "Disk 85% full"),
+            (NotificationLevel::Error, "Upload failed"),
+        ]
+        .into_iter()
+        .map(|(level, text)| {
+            Line::from(vec![
+                palette.status_indicator(level),
+                Span::styled(
+                    format!(" {text}"),
+                    Style::new().fg(palette.level_color(level)),
+                ),
+            ])
+        })
+        .chain(core::iter::once(Line::styled(
+            "  last checked 2m ago",
+            Style::new().fg(palette.muted),
+        )))
+        .collect::<Vec<_>>();
+        Paragraph::new(messages)
+            .block(theme.block().title("Status"))
+            .render(status_area, buf);
+
+        Gauge::default()
+            .block(theme.block().title("Progress"))
+            .gauge_style(Style::new().fg(palette.accent).bg(palette.selection))
+            .label(Span::styled("62%", theme.gauge_label_style()))
+            .ratio(0.62)
+            .render(gauge, buf);
+
+        Legend::new(self.theme, &[("q", "quit"), ("t", "next theme")]).render(footer, buf);
+    }
+}
+
+/// Shape of a text cursor drawn by an editor-like widget.
+///
+/// Pass it to [`Theme::cursor_style`] to get a matching themed style, e.g.
+/// [`Line`](Self::Line) in insert mode and [`Block`](Self::Block) in normal
+/// mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CursorShape {
+    /// A full cell highlighting the character under the cursor.
+    #[default]
+    Block,
+    /// A thin bar drawn between characters (e.g. `│`).
+    Line,
+    /// An underline beneath the character under the cursor.
+    Underline,
+}
+
+/// A single row of keybinding hints, such as `q quit · ? help`.
+///
+/// Each entry is a `(key, description)` pair drawn with
+/// [`Theme::legend_styles`]: the key in bold `accent`, the description in
+/// `muted`. Entries are separated by a `·` in the theme's `fg`. Only the
+/// first row of the area is drawn, and entries that don't fit are cut off.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+/// use ratatui_themes::{Legend, ThemeName};
+///
+/// let area = Rect::new(0, 0, 30, 1);
+/// let mut buf = Buffer::empty(area);
+/// Legend::new(ThemeName::Dracula, &[("q", "quit"), ("?", "help")]).render(area, &mut buf);
+///
+/// assert_eq!(buf[(0, 0)].symbol(), "q");
+/// assert_eq!(buf[(0, 0)].fg, ThemeName::Dracula.palette().accent);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Legend<'a> {
+    theme: ThemeName,
+    entries: &'a [(&'a str, &'a str)],
+}
+
+impl<'a> Legend<'a> {
+    /// Separator drawn between entries.
+    pub const SEPARATOR: &'static str = " · ";
+
+    /// Create a legend of `(key, description)` entries styled with `theme`.
+    #[must_use]
+    pub const fn new(theme: ThemeName, entries: &'a [(&'a str, &'a str)]) -> Self {
+        Self { theme, entries }
+    }
+}
+
+impl Widget for Legend<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &Legend<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let theme = Theme::new(self.theme);
+        let (key_style, description_style) = theme.legend_styles();
+        let separator = Span::styled(Legend::SEPARATOR, Style::new().fg(theme.palette().fg));
+
+        let mut spans = Vec::with_capacity(self.entries.len() * 3);
+        for (i, &(key, description)) in self.entries.iter().enumerate() {
+            if i > 0 {
+                spans.push(separator.clone());
+            }
+            spans.push(Span::styled(key, key_style));
+            spans.push(Span::styled(format!(" {description}"), description_style));
+        }
+        Line::from(spans).render(area, buf);
+    }
+}
+
+/// A scrollable list of every theme, for building a theme chooser.
+///
+/// Each row shows a theme's display name in that theme's own `accent`, so
+/// the list doubles as a preview. Names wider than the area are truncated
+/// with an ellipsis. The rest of the widget is drawn with the
+/// current theme: its `bg` fills the area and its `selection` highlights
+/// the selected row. The selection lives in a [`ThemeListState`], which also
+/// keeps track of the scroll offset so the selected row stays visible
+/// without scrolling past the last theme.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+/// use ratatui_themes::{ThemeList, ThemeListState, ThemeName};
+///
+/// let current = ThemeName::Nord;
+/// let mut state = ThemeListState::new(current);
+/// state.select_next();
+///
+/// let area = Rect::new(0, 0, 20, 5);
+/// let mut buf = Buffer::empty(area);
+/// ThemeList::new(current).render(area, &mut buf, &mut state);
+///
+/// assert_eq!(state.selected_theme(), ThemeName::CatppuccinMocha);
+/// assert_eq!(buf[(0, 3)].bg, current.palette().selection);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ThemeList {
+    theme: ThemeName,
+}
+
+impl ThemeList {
+    /// Create a list of all themes, drawn with the current `theme`.
+    #[must_use]
+    pub const fn new(theme: ThemeName) -> Self {
+        Self { theme }
+    }
+}
+
+/// Selection and scroll position of a [`ThemeList`].
+///
+/// The selected index is a position in [`ThemeName::all()`]. Moving past
+/// either end with [`select_next`](Self::select_next) or
+/// [`select_prev`](Self::select_prev) wraps around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ThemeListState {
+    selected: usize,
+    offset: usize,
+}
+
+impl ThemeListState {
+    /// Create a state with `theme` selected.
+    #[must_use]
+    pub const fn new(theme: ThemeName) -> Self {
+        Self {
+            selected: theme.index(),
+            offset: 0,
+        }
+    }
+
+    /// Returns the index of the selected theme in [`ThemeName::all()`].
+    #[must_use]
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Returns the selected theme.
+    #[must_use]
+    pub const fn selected_theme(&self) -> ThemeName {
+        ThemeName::all()[self.selected]
+    }
+
+    /// Returns the index of the first visible row.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Select `theme`.
+    pub fn select(&mut self, theme: ThemeName) {
+        self.selected = theme.index();
+    }
+
+    /// Select the next theme, wrapping from the last to the first.
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % ThemeName::COUNT;
+    }
+
+    /// Select the previous theme, wrapping from the first to the last.
+    pub fn select_prev(&mut self) {
+        self.selected = (self.selected + ThemeName::COUNT - 1) % ThemeName::COUNT;
+    }
+
+    /// Scroll just enough for the selected row to fit in `height` rows,
+    /// without leaving empty rows below the last theme.
+    fn scroll_to_selected(&mut self, height: usize) {
+        if self.selected < self.offset {
+            self.offset = self.selected;
+        } else if self.selected >= self.offset + height {
+            self.offset = self.selected + 1 - height;
+        }
+        self.offset = self.offset.min(ThemeName::COUNT.saturating_sub(height));
+    }
+}
+
+impl ratatui::widgets::StatefulWidget for ThemeList {
+    type State = ThemeListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        (&self).render(area, buf, state);
+    }
+}
+
+impl ratatui::widgets::StatefulWidget for &ThemeList {
+    type State = ThemeListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let palette = self.theme.palette();
+        buf.set_style(area, Style::new().fg(palette.fg).bg(palette.bg));
+        if area.is_empty() {
+            return;
+        }
+        state.scroll_to_selected(usize::from(area.height));
+
+        let rows = ThemeName::all().iter().enumerate().skip(state.offset);
+        for ((index, theme), row) in rows.zip(area.rows()) {
+            if index == state.selected {
+                buf.set_style(row, Style::new().bg(palette.selection));
+            }
+            Span::styled(
+                theme.display_name_truncated(usize::from(row.width)),
+                Style::new().fg(theme.palette().accent),
+            )
+            .render(row, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use ratatui::{buffer::Cell, widgets::StatefulWidget};
+
+    fn row_text(buf: &Buffer, y: u16) -> String {
+        (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect()
+    }
+
+    #[test]
+    fn test_picker_renders_title_and_swatches() {
+        let area = Rect::new(0, 0, 24, 12);
+        let mut buf = Buffer::empty(area);
+        ThemePicker::new(ThemeName::Nord).render(area, &mut buf);
+
+        assert!(row_text(&buf, 0).contains("Nord"));
+        assert!(row_text(&buf, 1).contains("accent"));
+        assert_eq!(buf[(1, 1)].fg, ThemeName::Nord.palette().accent);
+    }
+
+    #[test]
+    fn test_compare_renders_both_columns() {
+        let area = Rect::new(0, 0, 40, 12);
+        let mut buf = Buffer::empty(area);
+        ThemePicker::new(ThemeName::GruvboxDark)
+            .compare(ThemeName::GruvboxLight)
+            .render(area, &mut buf);
+
+        let title = row_text(&buf, 0);
+        assert!(title.contains("Gruvbox Dark"));
+        assert!(title.contains("Gruvbox Light"));
+        assert_eq!(buf[(21, 1)].fg, ThemeName::GruvboxLight.palette().accent);
+    }
+
+    #[test]
+    fn test_compare_ellipsizes_long_titles() {
+        let area = Rect::new(0, 0, 32, 12);
+        let mut buf = Buffer::empty(area);
+        ThemePicker::new(ThemeName::CatppuccinMocha)
+            .compare(ThemeName::RosePine)
+            .render(area, &mut buf);
+
+        let title = row_text(&buf, 0);
+        assert!(title.contains("Catppuccin Mo…"), "{title}");
+        assert!(title.contains("Rosé Pine"), "{title}");
+    }
+
+    #[test]
+    fn test_compare_custom_palette() {
+        let custom = ThemePalette {
+            accent: Color::Rgb(1, 2, 3),
+            ..ThemeName::Everforest.palette()
+        };
+        let area = Rect::new(0, 0, 40, 12);
+        let mut buf = Buffer::empty(area);
+        ThemePicker::new(ThemeName::Everforest)
+            .compare_custom("Everforest (tuned)", custom)
+            .render(area, &mut buf);
+
+        assert!(row_text(&buf, 0).contains("Everforest (tuned)"));
+        assert_eq!(buf[(21, 1)].fg, Color::Rgb(1, 2, 3));
+        assert_eq!(ThemePicker::default(), ThemePicker::new(ThemeName::Dracula));
+    }
+
+    #[test]
+    fn test_from_palette_uses_runtime_or_fallback_label() {
+        let palette = ThemeName::TokyoNight.palette().transparent_bg();
+        let area = Rect::new(0, 0, 24, 12);
+
+        let mut buf = Buffer::empty(area);
+        ThemePicker::from_palette(palette).render(area, &mut buf);
+        assert!(row_text(&buf, 0).contains(ThemePicker::CUSTOM_LABEL));
+        assert_eq!(buf[(1, 1)].fg, palette.accent);
+
+        let label = String::from("night-shift.toml");
+        let mut buf = Buffer::empty(area);
+        ThemePicker::custom(&label, palette).render(area, &mut buf);
+        assert!(row_text(&buf, 0).contains("night-shift.toml"));
+    }
+
+    #[test]
+    fn test_picker_fills_background_unless_transparent() {
+        let area = Rect::new(0, 0, 40, 12);
+        let mut buf = Buffer::empty(area);
+        ThemePicker::new(ThemeName::GruvboxDark)
+            .compare(ThemeName::GruvboxLight)
+            .render(area, &mut buf);
+
+        let (dark, light) = (
+            ThemeName::GruvboxDark.palette(),
+            ThemeName::GruvboxLight.palette(),
+        );
+        assert_eq!(buf[(0, 0)].bg, dark.bg);
+        assert_eq!(buf[(19, 11)].bg, dark.bg);
+        // Cells past the end of a swatch line still get the palette colors.
+        assert_eq!(buf[(18, 10)].bg, dark.bg);
+        assert_eq!(buf[(18, 10)].fg, dark.fg);
+        assert_eq!(buf[(39, 11)].bg, light.bg);
+
+        // Glyphs from a previous frame are cleared, not just restyled.
+        let mut buf = Buffer::filled(area, Cell::new("x"));
+        ThemePicker::new(ThemeName::GruvboxDark).render(area, &mut buf);
+        assert_eq!(buf[(18, 10)].symbol(), " ");
+        assert_eq!(buf[(18, 10)].bg, dark.bg);
+
+        let mut buf = Buffer::empty(area);
+        ThemePicker::new(ThemeName::GruvboxDark)
+            .opaque(false)
+            .render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].bg, Color::Reset);
+        assert_eq!(buf[(39, 11)].bg, Color::Reset);
+    }
+
+    #[test]
+    fn test_theme_list_highlights_and_scrolls_to_selection() {
+        let current = ThemeName::GruvboxDark;
+        let palette = current.palette();
+        let area = Rect::new(0, 0, 20, 4);
+        let mut state = ThemeListState::new(ThemeName::Nord);
+
+        let mut buf = Buffer::empty(area);
+        ThemeList::new(current).render(area, &mut buf, &mut state);
+        assert_eq!(row_text(&buf, 2).trim_end(), "Nord");
+        assert_eq!(buf[(0, 2)].fg, ThemeName::Nord.palette().accent);
+        assert_eq!(buf[(19, 2)].bg, palette.selection);
+        assert_eq!(buf[(19, 1)].bg, palette.bg);
+
+        state.select(ThemeName::TokyoNight);
+        let mut buf = Buffer::empty(area);
+        ThemeList::new(current).render(area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 4);
+        assert_eq!(row_text(&buf, 3).trim_end(), "Tokyo Night");
+        assert_eq!(buf[(0, 3)].bg, palette.selection);
+    }
+
+    #[test]
+    fn test_theme_list_clamps_offset_when_area_grows() {
+        let mut state = ThemeListState::new(ThemeName::Cyberpunk);
+        let small = Rect::new(0, 0, 20, 4);
+        ThemeList::new(ThemeName::Nord).render(small, &mut Buffer::empty(small), &mut state);
+        assert_eq!(state.offset(), ThemeName::COUNT - 4);
+
+        // A taller area pulls the list back so no rows are left empty.
+        let tall = Rect::new(0, 0, 20, 10);
+        let mut buf = Buffer::empty(tall);
+        ThemeList::new(ThemeName::Nord).render(tall, &mut buf, &mut state);
+        assert_eq!(state.offset(), ThemeName::COUNT - 10);
+        assert_eq!(row_text(&buf, 9).trim_end(), "Cyberpunk");
+
+        // Selecting an earlier theme in an area taller than the list shows every theme.
+        state.select(ThemeName::Kanagawa);
+        let all = Rect::new(0, 0, 20, 20);
+        let mut buf = Buffer::empty(all);
+        ThemeList::new(ThemeName::Nord).render(all, &mut buf, &mut state);
+        assert_eq!(state.offset(), 0);
+        assert_eq!(row_text(&buf, 0).trim_end(), "Dracula");
+    }
+
+    #[test]
+    fn test_theme_list_truncates_long_names() {
+        let area = Rect::new(0, 0, 8, 15);
+        let mut buf = Buffer::empty(area);
+        let mut state = ThemeListState::default();
+        ThemeList::new(ThemeName::Nord).render(area, &mut buf, &mut state);
+        assert_eq!(row_text(&buf, 3), "Catppuc…");
+        assert_eq!(row_text(&buf, 2).trim_end(), "Nord");
+    }
+
+    #[test]
+    fn test_theme_list_state_wraps() {
+        let mut state = ThemeListState::default();
+        state.select_prev();
+        assert_eq!(state.selected_theme(), ThemeName::Cyberpunk);
+        assert_eq!(state.selected(), ThemeName::COUNT - 1);
+        state.select_next();
+        assert_eq!(state.selected_theme(), ThemeName::Dracula);
+    }
+
+    #[test]
+    fn test_gradient_line_interpolates_and_degrades() {
+        let (start, end) = (Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0));
+
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buf = Buffer::empty(area);
+        GradientLine::new(start, end).render(area, &mut buf);
+        assert_eq!(buf[(0, 0)].bg, start);
+        assert_eq!(buf[(2, 0)].bg, Color::Rgb(100, 50, 0));
+        assert_eq!(buf[(4, 0)].bg, end);
+        assert_eq!(buf[(2, 1)].bg, Color::Reset);
+
+        let narrow = Rect::new(0, 0, 3, 1);
+        let mut buf = Buffer::empty(narrow);
+        GradientLine::new(start, end).render(narrow, &mut buf);
+        assert!((0..3).all(|x| buf[(x, 0)].bg == start));
+    }
+
+    #[test]
+    fn test_demo_renders_every_section() {
+        let area = Rect::new(0, 0, 60, 16);
+        let mut buf = Buffer::empty(area);
+        ThemeDemo::new(ThemeName::Nord).render(area, &mut buf);
+
+        let palette = ThemeName::Nord.palette();
+        let screen: Vec<String> = (0..area.height).map(|y| row_text(&buf, y)).collect();
+        assert!(screen[0].contains("Nord"));
+        assert!(screen.iter().any(|row| row.contains("> Drafts")));
+        assert!(screen.iter().any(|row| row.contains("Upload failed")));
+        assert!(screen.iter().any(|row| row.contains("62%")));
+        assert!(screen[15].contains("quit"));
+        assert_eq!(buf[(59, 15)].bg, palette.bg);
+    }
+
+    #[test]
+    fn test_legend_styles_keys_descriptions_and_separators() {
+        let area = Rect::new(0, 0, 24, 2);
+        let mut buf = Buffer::empty(area);
+        Legend::new(ThemeName::Nord, &[("q", "quit"), ("?", "help")]).render(area, &mut buf);
+
+        let palette = ThemeName::Nord.palette();
+        assert_eq!(row_text(&buf, 0).trim_end(), "q quit · ? help");
+        assert_eq!(buf[(0, 0)].fg, palette.accent);
+        assert!(buf[(0, 0)].modifier.contains(Modifier::BOLD));
+        assert_eq!(buf[(2, 0)].fg, palette.muted);
+        assert_eq!(buf[(7, 0)].fg, palette.fg);
+        assert_eq!(buf[(9, 0)].fg, palette.accent);
+        assert_eq!(row_text(&buf, 1).trim(), "");
+
+        let mut buf = Buffer::empty(area);
+        Legend::new(ThemeName::Nord, &[]).render(area, &mut buf);
+        assert_eq!(row_text(&buf, 0).trim(), "");
+    }
+
+    #[test]
+    fn test_compare_falls_back_when_narrow() {
+        let area = Rect::new(0, 0, 20, 12);
+        let mut buf = Buffer::empty(area);
+        ThemePicker::new(ThemeName::GruvboxDark)
+            .compare(ThemeName::GruvboxLight)
+            .render(area, &mut buf);
+
+        let title = row_text(&buf, 0);
+        assert!(title.contains("Gruvbox Dark"));
+        assert!(!title.contains("Light"));
+    }
+}